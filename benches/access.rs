@@ -0,0 +1,97 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use onceinit::OnceInit;
+use std::{sync::Barrier, thread};
+
+const THREADS: usize = 4;
+const READS_PER_THREAD: u64 = 100_000;
+
+static DATA: u64 = 42;
+static CELL: OnceInit<u64> = OnceInit::new(&DATA);
+static UNINIT: OnceInit<u64> = OnceInit::uninit();
+
+/// 单线程下的访问延迟。
+fn single_thread(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_thread");
+    group.bench_function("get", |b| b.iter(|| black_box(&CELL).get()));
+    group.bench_function("get_uninit", |b| b.iter(|| black_box(&UNINIT).get()));
+    group.bench_function("get_unchecked", |b| {
+        b.iter(|| unsafe { black_box(&CELL).get_unchecked() })
+    });
+    group.bench_function("is_initialized", |b| {
+        b.iter(|| black_box(&CELL).is_initialized())
+    });
+    // 快速路径被内联到循环中，反映调用处的代码大小。
+    group.bench_function("get_loop", |b| {
+        b.iter(|| {
+            (0..64)
+                .map(|_| *black_box(&CELL).get().unwrap())
+                .sum::<u64>()
+        })
+    });
+    group.finish();
+}
+
+/// 多线程同时初始化时的竞争。
+fn init_contention(c: &mut Criterion) {
+    c.bench_function("init_contention", |b| {
+        b.iter_batched(
+            || (OnceInit::<u64>::uninit(), Barrier::new(THREADS)),
+            |(cell, barrier)| {
+                thread::scope(|s| {
+                    for _ in 0..THREADS {
+                        s.spawn(|| {
+                            barrier.wait();
+                            black_box(cell.get_or_init(|| &DATA));
+                        });
+                    }
+                })
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+/// 初始化完成后，多线程读取的吞吐量。
+fn steady_state(c: &mut Criterion) {
+    let mut group = c.benchmark_group("steady_state");
+    group.throughput(Throughput::Elements(THREADS as u64 * READS_PER_THREAD));
+    group.bench_function("get", |b| {
+        b.iter(|| {
+            thread::scope(|s| {
+                for _ in 0..THREADS {
+                    s.spawn(|| {
+                        for _ in 0..READS_PER_THREAD {
+                            black_box(black_box(&CELL).get().unwrap());
+                        }
+                    });
+                }
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, single_thread, init_contention, steady_state);
+criterion_main!(benches);
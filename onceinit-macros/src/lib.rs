@@ -0,0 +1,246 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! [`onceinit`](https://docs.rs/onceinit) 的过程宏，请通过 `onceinit` 的 `macros` 特性使用。
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    Attribute, Data, DeriveInput, Expr, Fields, Ident, LitStr, Path, Token, Type, Visibility,
+};
+
+/// `global!` 中的一项：`#[attrs] vis static NAME: Type;`.
+struct GlobalItem {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    ty: Type,
+}
+
+impl Parse for GlobalItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![static]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            ty,
+        })
+    }
+}
+
+/// `global!` 的输入：可选的 `crate = path;`, 之后为若干 [`GlobalItem`].
+struct Globals {
+    krate: Path,
+    items: Vec<GlobalItem>,
+}
+
+impl Parse for Globals {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let krate = if input.peek(Token![crate]) && input.peek2(Token![=]) {
+            input.parse::<Token![crate]>()?;
+            input.parse::<Token![=]>()?;
+            let krate = input.parse()?;
+            input.parse::<Token![;]>()?;
+            krate
+        } else {
+            syn::parse_quote!(::onceinit)
+        };
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            items.push(input.parse()?);
+        }
+        Ok(Self { krate, items })
+    }
+}
+
+/// 声明全局实例。
+///
+/// ``` rust,ignore
+/// use onceinit::Global;
+///
+/// onceinit::global! {
+///     pub static LOGGER: dyn Logger;
+/// }
+///
+/// <dyn Logger>::init(Box::new(MyLogger)).unwrap();
+/// <dyn Logger>::get().unwrap().log("hello");
+/// ```
+///
+/// 将会生成：
+///
+/// - `pub static LOGGER: OnceInit<dyn Logger>`, 初始时未初始化；
+/// - `impl Global for dyn Logger`, 通过其 `init` 与 `get` 初始化与访问该实例。
+///
+/// 访问函数由 `onceinit::Global` 特型提供，不会与类型自身的固有方法冲突，使用前须将该特型引入作用域。
+/// 由于为类型实现了特型，`Type` 须为当前 crate 中定义的类型或特型对象。
+///
+/// 生成的代码默认通过 `::onceinit` 引用该 crate. 通过其他路径重导出时，可在开头指定：
+///
+/// ``` rust,ignore
+/// my_crate::global! {
+///     crate = ::my_crate::onceinit;
+///     pub static LOGGER: dyn Logger;
+/// }
+/// ```
+#[proc_macro]
+pub fn global(input: TokenStream) -> TokenStream {
+    let Globals { krate, items } = parse_macro_input!(input as Globals);
+    items
+        .into_iter()
+        .map(|item| {
+            let GlobalItem {
+                attrs,
+                vis,
+                name,
+                ty,
+            } = item;
+            quote! {
+                #(#attrs)*
+                #vis static #name: #krate::OnceInit<#ty> = #krate::OnceInit::uninit();
+                impl #krate::Global for #ty {
+                    #[inline]
+                    fn instance() -> &'static #krate::OnceInit<Self> {
+                        &#name
+                    }
+                }
+            }
+        })
+        .collect::<proc_macro2::TokenStream>()
+        .into()
+}
+
+/// 解析 `#[static_default(expr = "...")]` 中的表达式，不存在该属性时返回 `None`.
+fn static_default_expr(attrs: &[Attribute]) -> syn::Result<Option<Expr>> {
+    let mut expr = None;
+    for attr in attrs {
+        if !attr.path().is_ident("static_default") {
+            continue;
+        }
+        if expr.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "duplicate `static_default` attribute",
+            ));
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("expr") {
+                expr = Some(meta.value()?.parse::<LitStr>()?.parse::<Expr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `expr = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(expr)
+}
+
+fn derive_static_default_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`StaticDefault` cannot be derived for generic types: a `static` cannot be generic",
+        ));
+    }
+    let value = match static_default_expr(&input.attrs)? {
+        Some(expr) => quote! { #expr },
+        None => {
+            let Data::Struct(data) = &input.data else {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "deriving `StaticDefault` for enums and unions requires `#[static_default(expr = \"...\")]`",
+                ));
+            };
+            let exprs = data
+                .fields
+                .iter()
+                .map(|field| {
+                    static_default_expr(&field.attrs)?.ok_or_else(|| {
+                        syn::Error::new(
+                            field.span(),
+                            "field has no const default: add `#[static_default(expr = \"...\")]` to the field or the type",
+                        )
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            match &data.fields {
+                Fields::Named(fields) => {
+                    let names = fields.named.iter().map(|field| &field.ident);
+                    quote! { #name { #(#names: #exprs),* } }
+                }
+                Fields::Unnamed(_) => quote! { #name(#(#exprs),*) },
+                Fields::Unit => quote! { #name },
+            }
+        }
+    };
+    Ok(quote! {
+        unsafe impl ::onceinit::StaticDefault for #name {
+            #[inline]
+            fn static_default() -> &'static Self {
+                static DEFAULT: #name = #value;
+                &DEFAULT
+            }
+        }
+    })
+}
+
+/// 为类型实现 `StaticDefault`, 默认值存储在生成的 `static` 中，不会泄漏内存。
+///
+/// 默认值须为常量表达式，由以下方式给出：
+///
+/// - 类型上的 `#[static_default(expr = "...")]`, 作为整个默认值；
+/// - 否则，结构体的每个字段上的 `#[static_default(expr = "...")]`, 作为该字段的默认值。
+///
+/// 单元结构体无需任何属性。枚举须在类型上给出表达式。不支持泛型类型，类型须为 `Sync` 的。
+///
+/// ``` rust,ignore
+/// #[derive(onceinit::StaticDefault)]
+/// struct Config {
+///     #[static_default(expr = "3")]
+///     retries: u32,
+///     #[static_default(expr = "\"localhost\"")]
+///     host: &'static str,
+/// }
+///
+/// #[derive(onceinit::StaticDefault)]
+/// #[static_default(expr = "Level::Info")]
+/// enum Level {
+///     Info,
+///     Debug,
+/// }
+/// ```
+#[proc_macro_derive(StaticDefault, attributes(static_default))]
+pub fn derive_static_default(input: TokenStream) -> TokenStream {
+    derive_static_default_impl(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,87 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use onceinit::{Global, OnceInitError};
+
+pub trait Greeter: Send + Sync {
+    fn greet(&self) -> &'static str;
+}
+struct Hello;
+impl Greeter for Hello {
+    fn greet(&self) -> &'static str {
+        "hello"
+    }
+}
+
+pub struct Config {
+    pub retries: u32,
+}
+impl Config {
+    /// 与生成的访问函数重名的固有方法。
+    pub fn get() -> u32 {
+        0
+    }
+}
+
+mod reexport {
+    pub use onceinit;
+}
+pub struct Renamed;
+
+onceinit::global! {
+    /// 全局的 `Greeter`.
+    pub static GREETER: dyn Greeter;
+    static CONFIG: Config;
+}
+onceinit::global! {
+    crate = reexport::onceinit;
+    static RENAMED: Renamed;
+}
+
+#[test]
+fn test_global_trait_object() {
+    assert!(<dyn Greeter>::get().is_none());
+    <dyn Greeter>::init(Box::new(Hello)).unwrap();
+    assert_eq!(<dyn Greeter>::get().unwrap().greet(), "hello");
+    assert_eq!(GREETER.get().unwrap().greet(), "hello");
+    assert!(matches!(
+        <dyn Greeter>::init(Box::new(Hello)),
+        Err(OnceInitError::DataInitialized)
+    ));
+}
+
+#[test]
+fn test_global_struct() {
+    // 固有方法优先，生成的访问函数不会与其冲突。
+    assert_eq!(Config::get(), 0);
+    assert!(<Config as Global>::get().is_none());
+    Config::init(Box::new(Config { retries: 3 })).unwrap();
+    assert_eq!(<Config as Global>::get().unwrap().retries, 3);
+    assert_eq!(CONFIG.get().unwrap().retries, 3);
+}
+
+#[test]
+fn test_global_crate_path() {
+    Renamed::init(Box::new(Renamed)).unwrap();
+    assert!(Renamed::get().is_some());
+    assert!(RENAMED.is_initialized());
+}
@@ -0,0 +1,72 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use onceinit::{OnceInit, StaticDefault};
+
+#[derive(StaticDefault)]
+struct Config {
+    #[static_default(expr = "3")]
+    retries: u32,
+    #[static_default(expr = "\"localhost\"")]
+    host: &'static str,
+}
+
+#[derive(StaticDefault)]
+struct Pair(
+    #[static_default(expr = "1")] u8,
+    #[static_default(expr = "-1")] i8,
+);
+
+#[derive(StaticDefault)]
+struct Marker;
+
+#[derive(Debug, PartialEq, StaticDefault)]
+#[static_default(expr = "Level::SENTINEL")]
+enum Level {
+    Info,
+    Debug,
+}
+
+impl Level {
+    const SENTINEL: Level = Level::Info;
+}
+
+#[test]
+fn test_derive_struct() {
+    static CONFIG: OnceInit<Config> = OnceInit::uninit();
+    assert_eq!((CONFIG.retries, CONFIG.host), (3, "localhost"));
+    assert!(core::ptr::eq(
+        Config::static_default(),
+        CONFIG.get_or_default()
+    ));
+    let pair: OnceInit<Pair> = OnceInit::uninit();
+    assert_eq!((pair.0, pair.1), (1, -1));
+    let _: &Marker = Marker::static_default();
+}
+
+#[test]
+fn test_derive_container_expr() {
+    static LEVEL: OnceInit<Level> = OnceInit::uninit();
+    assert_eq!(*LEVEL, Level::Info);
+    LEVEL.init(&Level::Debug).unwrap();
+    assert_eq!(*LEVEL, Level::Debug);
+}
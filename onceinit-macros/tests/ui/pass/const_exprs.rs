@@ -0,0 +1,43 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use onceinit::{OnceInit, StaticDefault};
+
+const fn default_port() -> u16 {
+    8080
+}
+
+#[derive(StaticDefault)]
+struct Server {
+    #[static_default(expr = "default_port()")]
+    port: u16,
+    #[static_default(expr = "[0; 4]")]
+    addr: [u8; 4],
+    #[static_default(expr = "None")]
+    name: Option<&'static str>,
+}
+
+static SERVER: OnceInit<Server> = OnceInit::uninit();
+
+fn main() {
+    assert_eq!((SERVER.port, SERVER.addr, SERVER.name), (8080, [0; 4], None));
+}
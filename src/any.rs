@@ -0,0 +1,108 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::OnceInit;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use crate::OnceInitError;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use alloc::boxed::Box;
+use core::{
+    any::{type_name, Any, TypeId},
+    error::Error,
+    fmt::Display,
+};
+
+#[derive(Debug)]
+/// # `DowncastError`
+/// 将 [`OnceInit<dyn Any + Send + Sync>`](OnceInit) 的内部数据向下转型时可能返回该错误。
+///
+/// 其中的类型名均为期望的类型，即 [`get_downcast`](OnceInit::get_downcast) 的类型参数。
+/// 由于 [`Any`] 不提供实际类型的名称，实际存储的类型只能以 [`TypeId`] 给出。
+#[non_exhaustive]
+pub enum DowncastError {
+    /// 数据未被初始化。
+    #[non_exhaustive]
+    Uninitialized { expected: &'static str },
+    /// 数据已被初始化，但类型不符。`actual` 为实际存储的数据的 [`TypeId`].
+    #[non_exhaustive]
+    WrongType {
+        expected: &'static str,
+        actual: TypeId,
+    },
+}
+
+impl Display for DowncastError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            DowncastError::Uninitialized { expected } => {
+                write!(f, "data of type `{expected}` is uninitialized.")
+            }
+            DowncastError::WrongType { expected, actual } => {
+                write!(f, "data is not of type `{expected}`, but of {actual:?}.")
+            }
+        }
+    }
+}
+impl Error for DowncastError {}
+
+impl OnceInit<dyn Any + Send + Sync> {
+    /// 返回向下转型为 `U` 的内部数据。
+    ///
+    /// 若未初始化或类型不符，则返回 [`DowncastError`].
+    #[inline]
+    pub fn get_downcast<U: Any>(&self) -> Result<&'static U, DowncastError> {
+        let expected = type_name::<U>();
+        let data = self
+            .get()
+            .map_err(|_| DowncastError::Uninitialized { expected })?;
+        data.downcast_ref().ok_or(DowncastError::WrongType {
+            expected,
+            actual: (*data).type_id(),
+        })
+    }
+    /// 以具体类型的 [`Box`] 初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_boxed_any<U: Any + Send + Sync>(&self, value: Box<U>) -> Result<(), OnceInitError> {
+        self.init_boxed(value)
+    }
+}
+
+macro_rules! impl_downcast {
+    ($($dyn_any:ty),+) => {$(
+        impl OnceInit<$dyn_any> {
+            /// 返回向下转型为 `U` 的内部数据，未初始化或类型不符时返回 [`None`].
+            ///
+            /// 需要区分两种失败情形时，可使用 [`get_downcast`](OnceInit::get_downcast).
+            #[inline]
+            pub fn downcast<U: Any>(&self) -> Option<&'static U> {
+                self.get().ok()?.downcast_ref()
+            }
+            /// 返回内部数据是否已初始化且类型为 `U`.
+            #[inline]
+            pub fn is<U: Any>(&self) -> bool {
+                self.get().is_ok_and(|data| data.is::<U>())
+            }
+        }
+    )+};
+}
+impl_downcast!(dyn Any, dyn Any + Send, dyn Any + Send + Sync);
@@ -0,0 +1,84 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::OnceInit;
+use core::{fmt::Debug, ops::Deref};
+
+/// # `OnceInitConst`
+/// 总是已初始化的 [`OnceInit`], 只能通过 [`new`](Self::new) 构造。
+///
+/// 构造即保证了数据存在，因此 [`get_const`](Self::get_const) 无需读取状态，可以在 `const fn` 与常量中使用。
+/// 其余 api 通过解引用为 [`OnceInit`] 使用。
+///
+/// [`OnceInit`] 具有内部可变性，常量求值无法读取 `static` 中的值，因此在常量中使用时须将其声明为 `const`.
+///
+/// ``` rust
+/// use onceinit::OnceInitConst;
+///
+/// const LIMIT: OnceInitConst<u32> = OnceInitConst::new(&16);
+/// const DOUBLE_LIMIT: u32 = *LIMIT.get_const() * 2;
+/// assert_eq!(DOUBLE_LIMIT, 32);
+/// assert_eq!(*LIMIT.get().unwrap(), 16);
+/// ```
+#[repr(transparent)]
+pub struct OnceInitConst<T: ?Sized + 'static> {
+    cell: OnceInit<T>,
+}
+
+impl<T: ?Sized> OnceInitConst<T> {
+    /// 返回以 `data` 初始化的实例。
+    #[inline]
+    pub const fn new(data: &'static T) -> Self {
+        Self {
+            cell: OnceInit::new(data),
+        }
+    }
+    /// 返回内部数据，不读取状态，也不会等待。
+    #[inline]
+    pub const fn get_const(&self) -> &'static T {
+        // 只能通过 `new` 构造，且不提供可变访问，内部数据总是存在。
+        match unsafe { *self.cell.data.get() } {
+            Some(data) => data,
+            None => unreachable!(),
+        }
+    }
+    /// 返回内部的 [`OnceInit`].
+    #[inline]
+    pub const fn as_once_init(&self) -> &OnceInit<T> {
+        &self.cell
+    }
+}
+impl<T: ?Sized> Deref for OnceInitConst<T> {
+    type Target = OnceInit<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.cell
+    }
+}
+impl<T: ?Sized + Debug> Debug for OnceInitConst<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OnceInitConst")
+            .field(&self.get_const())
+            .finish()
+    }
+}
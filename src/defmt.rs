@@ -0,0 +1,61 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! `defmt` 支持，适用于嵌入式环境下的日志。
+
+use crate::{OnceInit, OnceInitError, OnceInitState};
+use defmt::{Format, Formatter};
+
+/// 与 [`Display`](core::fmt::Display) 的输出相同。
+impl Format for OnceInitState {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            OnceInitState::UNINITIALIZED => defmt::write!(f, "uninitialized"),
+            OnceInitState::INITIALIZING => defmt::write!(f, "initializing"),
+            OnceInitState::INITIALIZED => defmt::write!(f, "initialized"),
+        }
+    }
+}
+
+/// 与未启用 `locale` 特性时 [`Display`](core::fmt::Display) 的输出相同。
+impl Format for OnceInitError {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            OnceInitError::DataUninitialized => defmt::write!(f, "data is uninitialized."),
+            OnceInitError::DataInitialized => {
+                defmt::write!(f, "data has already been initialized.")
+            }
+            OnceInitError::TimedOut => defmt::write!(f, "timed out waiting for initialization."),
+        }
+    }
+}
+
+/// 与 [`Debug`](core::fmt::Debug) 一致，不会等待正在进行的初始化。
+impl<T: ?Sized + Format> Format for OnceInit<T> {
+    fn format(&self, f: Formatter<'_>) {
+        match self.peek() {
+            Ok(data) => defmt::write!(f, "OnceInit({})", data),
+            Err(OnceInitState::INITIALIZING) => defmt::write!(f, "OnceInit(<initializing>)"),
+            Err(_) => defmt::write!(f, "OnceInit(<uninit>)"),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 诊断用的 api.
+
+use crate::OnceInit;
+#[cfg(feature = "diagnostics")]
+use crate::{OnceInitError, INITIALIZED, INITIALIZING};
+#[cfg(all(feature = "diagnostics", target_has_atomic = "64"))]
+use core::sync::atomic::AtomicU64;
+#[cfg(feature = "diagnostics")]
+use core::sync::atomic::Ordering;
+
+/// 所有 [`get_with_spin_count`](OnceInit::get_with_spin_count) 调用的自旋次数之和，需启用 `diagnostics` 特性。
+///
+/// 只用于统计，以 [`Relaxed`](Ordering::Relaxed) 顺序累加。
+#[cfg(all(feature = "diagnostics", target_has_atomic = "64"))]
+pub static SPIN_COUNT_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+impl<T: ?Sized> OnceInit<T> {
+    /// 已初始化时返回内部数据占用的字节数（[`core::mem::size_of_val`]）, 否则返回 [`None`].
+    ///
+    /// 对于 `dyn Trait` 与 `[T]` 等动态大小类型，返回的是实际数据的大小，如 `str` 的字节长度。
+    /// 只计算数据本身，不包括其间接持有的堆内存（如 `Vec` 的缓冲区）。正在初始化时会等待其完成。
+    #[inline]
+    pub fn memory_usage(&self) -> Option<usize> {
+        self.get().ok().map(core::mem::size_of_val)
+    }
+    /// 同 [`get`](Self::get), 并返回等待正在进行的初始化时自旋的次数，需启用 `diagnostics` 特性。
+    ///
+    /// 状态一开始就是已初始化或未初始化时，次数总为 `0`. 为使次数有意义，该函数总是自旋等待，
+    /// 即使启用了 `atomic-wait` 特性。
+    #[cfg_attr(
+        target_has_atomic = "64",
+        doc = "次数同时会累加到 [`SPIN_COUNT_TOTAL`]."
+    )]
+    #[cfg(feature = "diagnostics")]
+    pub fn get_with_spin_count(&self) -> (Result<&'static T, OnceInitError>, u64) {
+        let mut spins = 0;
+        let state = loop {
+            match self.state.load(Ordering::Acquire) {
+                INITIALIZING => {
+                    spins += 1;
+                    core::hint::spin_loop();
+                }
+                state => break state,
+            }
+        };
+        #[cfg(target_has_atomic = "64")]
+        SPIN_COUNT_TOTAL.fetch_add(spins, Ordering::Relaxed);
+        let result = match state {
+            INITIALIZED => Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
+            _ => Err(OnceInitError::DataUninitialized),
+        };
+        (result, spins)
+    }
+}
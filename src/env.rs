@@ -0,0 +1,85 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInit, OnceInitError};
+use core::{fmt::Display, str::FromStr};
+use std::{env::VarError, error::Error};
+
+#[derive(Debug)]
+/// # `EnvInitError`
+/// 以环境变量初始化 [`OnceInit`] 时可能返回该错误，见 [`init_from_env_parse`](OnceInit::init_from_env_parse).
+pub enum EnvInitError<E> {
+    /// 环境变量不存在或不是有效的 Unicode.
+    Var(VarError),
+    /// 环境变量的值无法解析。
+    Parse(E),
+    /// 数据已被初始化。
+    Init(OnceInitError),
+}
+
+impl<E: Display> Display for EnvInitError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            EnvInitError::Var(e) => e.fmt(f),
+            EnvInitError::Parse(e) => write!(f, "failed to parse environment variable: {e}"),
+            EnvInitError::Init(e) => e.fmt(f),
+        }
+    }
+}
+impl<E: Error + 'static> Error for EnvInitError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EnvInitError::Var(e) => Some(e),
+            EnvInitError::Parse(e) => Some(e),
+            EnvInitError::Init(e) => Some(e),
+        }
+    }
+}
+
+impl<T: FromStr> OnceInit<T> {
+    /// 读取环境变量 `var`, 以 [`FromStr`] 解析后初始化内部数据，只可调用一次，成功则初始化完成。
+    ///
+    /// 只有成功初始化的线程会读取、解析环境变量并泄漏解析结果，因此至多泄漏一次。
+    /// 读取或解析失败时数据保持未初始化。
+    ///
+    /// ``` rust
+    /// use onceinit::OnceInit;
+    ///
+    /// static WORKERS: OnceInit<u32> = OnceInit::uninit();
+    ///
+    /// // 环境变量不存在或无法解析时，数据保持未初始化，可以回退到默认值。
+    /// let _ = WORKERS.init_from_env_parse("APP_WORKERS");
+    /// let workers = WORKERS.get().copied().unwrap_or(1);
+    /// println!("workers: {workers}");
+    /// ```
+    pub fn init_from_env_parse(&self, var: &str) -> Result<(), EnvInitError<T::Err>> {
+        let make_data = || {
+            let value = std::env::var(var).map_err(EnvInitError::Var)?;
+            let data = value.parse().map_err(EnvInitError::Parse)?;
+            Ok(&*Box::leak(Box::new(data)))
+        };
+        match self.try_init_reporting_internal(make_data) {
+            Ok(result) => result,
+            Err(_) => Err(EnvInitError::Init(OnceInitError::DataInitialized)),
+        }
+    }
+}
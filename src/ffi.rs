@@ -0,0 +1,167 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{ConstStaticDefault, OnceInit, OnceInitError};
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use alloc::ffi::CString;
+use core::ffi::{c_char, CStr};
+
+impl OnceInit<CStr> {
+    /// 以 `'static` C 字符串初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    pub fn init_cstr(&self, s: &'static CStr) -> Result<(), OnceInitError> {
+        self.init(s)
+    }
+    /// 以 [`CString`] 初始化内部数据，只可调用一次。
+    ///
+    /// 成功时会泄漏 `s` 的缓冲区；失败时原样返回 `s`.
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_cstring(&self, s: CString) -> Result<(), CString> {
+        self.try_init_boxed_take(s.into_boxed_c_str())
+            .map(|_| ())
+            .map_err(CString::from)
+    }
+    /// 返回内部 C 字符串的指针，若未初始化，则返回空 C 字符串的指针。
+    ///
+    /// 返回的指针总是非空且以 NUL 结尾，在程序运行期间始终有效，可以直接交给 C 代码。
+    #[inline]
+    pub fn as_ptr(&self) -> *const c_char {
+        self.get_or_default().as_ptr()
+    }
+}
+/// 返回空 C 字符串，即仅含 NUL 的字符串。
+impl ConstStaticDefault for CStr {
+    const STATIC_DEFAULT: &'static Self = c"";
+}
+
+/// 为 [`OnceInit`](crate::OnceInit) 类型的静态变量生成 `extern "C"` 访问函数。
+///
+/// ``` rust
+/// use onceinit::OnceInit;
+///
+/// #[repr(C)]
+/// pub struct Config {
+///     pub baud_rate: u32,
+/// }
+/// pub static CONFIG: OnceInit<Config> = OnceInit::uninit();
+///
+/// onceinit::extern_accessors!(CONFIG: Config => mycrate_config_get, mycrate_config_init);
+///
+/// static DEFAULT_CONFIG: Config = Config { baud_rate: 115200 };
+/// assert!(mycrate_config_get().is_null());
+/// assert_eq!(unsafe { mycrate_config_init(&DEFAULT_CONFIG) }, 0);
+/// assert_eq!(unsafe { (*mycrate_config_get()).baud_rate }, 115200);
+/// assert_eq!(unsafe { mycrate_config_init(&DEFAULT_CONFIG) }, 2);
+/// ```
+///
+/// 将会生成以下两个函数：
+///
+/// - `extern "C" fn mycrate_config_get() -> *const Config`: 返回内部数据的指针，未初始化时返回空指针；
+/// - `unsafe extern "C" fn mycrate_config_init(ptr: *const Config) -> i32`: 以 `ptr` 初始化数据，
+///   成功返回 `0`, 失败返回 [`OnceInitError::code`](crate::OnceInitError::code).
+///   其安全性要求同 [`OnceInit::init_raw`](crate::OnceInit::init_raw).
+#[macro_export]
+macro_rules! extern_accessors {
+    ($cell:path : $ty:ty => $get:ident, $init:ident $(,)?) => {
+        #[no_mangle]
+        pub extern "C" fn $get() -> *const $ty {
+            $crate::OnceInit::<$ty>::as_ptr(&$cell)
+        }
+        /// # Safety
+        ///
+        /// 见 `OnceInit::init_raw`.
+        #[no_mangle]
+        pub unsafe extern "C" fn $init(ptr: *const $ty) -> i32 {
+            match unsafe { $crate::OnceInit::<$ty>::init_raw(&$cell, ptr) } {
+                ::core::result::Result::Ok(()) => 0,
+                ::core::result::Result::Err(e) => e.code(),
+            }
+        }
+    };
+}
+
+/// 声明进程内唯一的 [`OnceInit`](crate::OnceInit), 生成返回其引用的访问函数。
+///
+/// 当进程中存在该 crate 的多个副本（如依赖了多个版本，或同时链接进了宿主程序与 cdylib 插件）时，
+/// 每个副本中的静态变量都是独立的。该宏通过导出的符号（锚点）使各副本访问同一份存储。
+///
+/// 有两种形式：
+///
+/// ``` rust,ignore
+/// // 定义锚点并导出符号，整个进程中只能有一处定义。
+/// onceinit::process_singleton! {
+///     pub fn logger() -> dyn Logger = "myapp_logger_anchor";
+/// }
+/// // 引用其他地方定义的锚点，可以有任意多处。生成的访问函数是 `unsafe` 的。
+/// onceinit::process_singleton! {
+///     pub unsafe fn logger() -> dyn Logger = extern "myapp_logger_anchor";
+/// }
+/// // 调用者须保证上述约束，见下文。
+/// let logger = unsafe { logger() };
+/// ```
+///
+/// 对于动态库插件，宿主程序须导出定义锚点的符号（如在 Linux 上以 `-rdynamic` 链接）。
+///
+/// # Safety
+///
+/// 引用锚点的形式生成的是 `unsafe fn`, 编译器与链接器均不检查符号背后的类型。调用者须保证：
+///
+/// - 符号名在整个进程中唯一，且恰有一处以定义锚点的形式定义；
+/// - 各处声明的 `$ty` 完全一致，且各副本使用同一版本的 `onceinit`, 以保证 [`OnceInit`](crate::OnceInit) 的布局相同。
+///
+/// 否则生成的访问函数会以错误的类型读取锚点，行为未定义。
+///
+/// # 等待初始化
+///
+/// 唤醒等待者的登记表（[`wait_initialized`](crate::OnceInit::wait_initialized)、`poll` 等异步接口所用的登记表，
+/// 以及 `atomic-wait` 特性所用的计数器）在每个副本中各有一份，不随锚点共享。
+/// 在一个副本中阻塞等待的线程不会被另一个副本中结束的初始化唤醒，可能永远挂起。
+/// 跨副本等待时只能依赖自旋：未启用 `atomic-wait` 时，[`get`](crate::OnceInit::get) 以自旋等待正在进行的初始化，可以跨副本使用。
+#[cfg(feature = "process-singleton")]
+#[macro_export]
+macro_rules! process_singleton {
+    ($(#[$attr:meta])* $vis:vis unsafe fn $name:ident() -> $ty:ty = extern $sym:literal $(;)?) => {
+        $(#[$attr])*
+        ///
+        /// # Safety
+        ///
+        #[doc = concat!("符号 `", $sym, "` 须恰有一处以相同的类型定义，见 `onceinit::process_singleton!`.")]
+        #[inline]
+        $vis unsafe fn $name() -> &'static $crate::OnceInit<$ty> {
+            extern "Rust" {
+                #[link_name = $sym]
+                static ANCHOR: $crate::OnceInit<$ty>;
+            }
+            unsafe { &ANCHOR }
+        }
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident() -> $ty:ty = $sym:literal $(;)?) => {
+        $(#[$attr])*
+        #[inline]
+        $vis fn $name() -> &'static $crate::OnceInit<$ty> {
+            #[export_name = $sym]
+            static ANCHOR: $crate::OnceInit<$ty> = $crate::OnceInit::uninit();
+            &ANCHOR
+        }
+    };
+}
@@ -0,0 +1,51 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::OnceInit;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use crate::OnceInitError;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use alloc::boxed::Box;
+
+/// # `Global`
+/// 拥有一个全局实例的类型，通常由 `global!` 宏实现。
+///
+/// 访问函数以特型而非固有方法的形式提供，因此不会与类型自身的 `init` 或 `get` 冲突；
+/// 发生重名时，固有方法优先，可通过 `<T as Global>::get()` 调用本特型的版本。
+///
+/// [`init`](Global::init) 需要 `alloc` 特性（或标准库）。在没有分配器的环境中，可通过
+/// [`instance`](Global::instance) 以 `&'static` 引用初始化。
+pub trait Global: 'static {
+    /// 返回存放全局实例的 [`OnceInit`].
+    fn instance() -> &'static OnceInit<Self>;
+    /// 初始化全局实例，只可调用一次。
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    fn init(val: Box<Self>) -> Result<(), OnceInitError> {
+        Self::instance().init_boxed(val)
+    }
+    /// 返回全局实例，未初始化时返回 [`None`].
+    #[inline]
+    fn get() -> Option<&'static Self> {
+        Self::instance().get().ok()
+    }
+}
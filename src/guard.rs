@@ -0,0 +1,76 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInit, INITIALIZED, UNINITIALIZED};
+use core::{fmt::Debug, ops::Deref};
+
+/// # `InitGuard`
+/// 临时初始化 [`OnceInit`] 的守卫，见 [`scope_init`](OnceInit::scope_init).
+///
+/// 守卫被丢弃时，[`OnceInit`] 恢复为调用 [`scope_init`](OnceInit::scope_init) 之前的状态。
+/// 守卫存活期间可通过解引用访问设置的数据。
+pub struct InitGuard<'a, T: ?Sized + 'static> {
+    cell: &'a mut OnceInit<T>,
+    data: &'static T,
+    previous: Option<&'static T>,
+}
+
+impl<T: ?Sized> Deref for InitGuard<'_, T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        self.data
+    }
+}
+impl<T: ?Sized + Debug> Debug for InitGuard<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("InitGuard").field(&self.data).finish()
+    }
+}
+impl<T: ?Sized> Drop for InitGuard<'_, T> {
+    fn drop(&mut self) {
+        *self.cell.data.get_mut() = self.previous;
+        *self.cell.state.get_mut() = match self.previous {
+            Some(_) => INITIALIZED,
+            None => UNINITIALIZED,
+        };
+    }
+}
+
+impl<T: ?Sized> OnceInit<T> {
+    /// 临时以 `data` 初始化内部数据，返回的 [`InitGuard`] 被丢弃时恢复原先的状态。
+    ///
+    /// 通常用于测试：原先未初始化时，守卫丢弃后 [`OnceInit`] 重新变为未初始化；
+    /// 原先已初始化时，守卫存活期间数据被暂时替换，丢弃后恢复为原先的数据。
+    ///
+    /// 由于需要 `&mut self`, 该函数不会与其他读写操作竞争。
+    #[inline]
+    pub fn scope_init(&mut self, data: &'static T) -> InitGuard<'_, T> {
+        let previous = self.data.get_mut().replace(data);
+        *self.state.get_mut() = INITIALIZED;
+        InitGuard {
+            cell: self,
+            data,
+            previous,
+        }
+    }
+}
@@ -0,0 +1,90 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::OnceInit;
+use core::{fmt::Debug, ops::Deref};
+
+/// # `Lazy`
+/// 首次访问时才初始化的 [`OnceInit`].
+///
+/// 可用于替代 `lazy_static!` 或 `once_cell::sync::Lazy`:
+///
+/// ``` rust
+/// use onceinit::Lazy;
+/// use std::collections::HashMap;
+///
+/// // lazy_static! {
+/// //     static ref PRIMES: HashMap<u32, &'static str> = {
+/// //         let mut m = HashMap::new();
+/// //         m.insert(2, "two");
+/// //         m.insert(3, "three");
+/// //         m
+/// //     };
+/// // }
+/// static PRIMES: Lazy<HashMap<u32, &'static str>> = Lazy::new(|| {
+///     let mut m = HashMap::new();
+///     m.insert(2, "two");
+///     m.insert(3, "three");
+///     Box::leak(Box::new(m))
+/// });
+///
+/// assert_eq!(PRIMES.get(&2), Some(&"two"));
+/// assert_eq!(PRIMES.len(), 2);
+/// ```
+///
+/// 初始化函数 `F` 默认为函数指针，不捕获环境的闭包可以在 `const` 上下文中转换为函数指针。
+/// 多个线程同时首次访问时，只有一个线程会调用初始化函数。
+pub struct Lazy<T: ?Sized + 'static, F = fn() -> &'static T> {
+    cell: OnceInit<T>,
+    init: F,
+}
+
+impl<T: ?Sized, F> Lazy<T, F> {
+    /// 以初始化函数 `init` 构造 [`Lazy`].
+    #[inline]
+    pub const fn new(init: F) -> Self {
+        Self {
+            cell: OnceInit::uninit(),
+            init,
+        }
+    }
+}
+impl<T: ?Sized, F: Fn() -> &'static T> Lazy<T, F> {
+    /// 强制初始化并返回内部数据，等同于解引用。
+    #[inline]
+    pub fn force(this: &Self) -> &'static T {
+        this.cell.get_or_init(|| (this.init)())
+    }
+}
+impl<T: ?Sized, F: Fn() -> &'static T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &'static Self::Target {
+        Self::force(self)
+    }
+}
+impl<T: ?Sized + Debug, F> Debug for Lazy<T, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Lazy").field(&self.cell).finish()
+    }
+}
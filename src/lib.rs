@@ -22,10 +22,88 @@
 
 # ![doc = include_str!("../README.md")]
 #![cfg_attr(feature = "no_std", no_std)]
+#![cfg_attr(feature = "nightly", feature(try_trait_v2))]
 #[cfg(all(not(feature = "no_std"), test))]
 mod tests;
 
-#[cfg(feature = "alloc")]
+mod any;
+mod constant;
+#[cfg(feature = "defmt")]
+mod defmt;
+mod diagnostics;
+#[cfg(not(feature = "no_std"))]
+mod env;
+mod ffi;
+mod global;
+mod guard;
+mod lazy;
+#[cfg(feature = "locale")]
+mod locale;
+#[cfg(all(feature = "once_cell", not(feature = "no_std")))]
+mod once_cell;
+#[cfg(not(feature = "no_std"))]
+mod once_lock;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+mod order;
+#[cfg(feature = "atomic-wait")]
+mod os_wait;
+mod panicking;
+#[cfg(not(feature = "no_std"))]
+mod path;
+mod primitive;
+mod reader;
+mod scoped;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+mod send;
+#[cfg(feature = "serde")]
+mod serialize;
+mod slice;
+mod string;
+#[cfg(feature = "test-util")]
+mod test_util;
+mod token;
+mod trait_object;
+#[cfg(feature = "nightly")]
+mod try_trait;
+#[cfg(feature = "ufmt")]
+mod ufmt;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+mod wait;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+mod waiter;
+
+pub use any::DowncastError;
+pub use constant::OnceInitConst;
+#[cfg(all(feature = "diagnostics", target_has_atomic = "64"))]
+pub use diagnostics::SPIN_COUNT_TOTAL;
+#[cfg(not(feature = "no_std"))]
+pub use env::EnvInitError;
+pub use global::Global;
+pub use guard::InitGuard;
+pub use lazy::Lazy;
+#[cfg(feature = "locale")]
+pub use locale::set_error_messages;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+pub use order::InitOrder;
+pub use panicking::{PanicOnDoubleInit, PanicOnUninit, PanickingOnceInit};
+pub use reader::OnceInitReader;
+pub use scoped::OnceInitRef;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+pub use send::SendOnceInit;
+pub use token::InitToken;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+pub use wait::Wait;
+
+#[cfg(feature = "macros")]
+pub use onceinit_macros::{global, StaticDefault};
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "const-default")]
+    pub use const_default::ConstDefault;
+}
+
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
 extern crate alloc;
 
 use ::core::{
@@ -33,45 +111,120 @@ use ::core::{
     error::Error,
     fmt::Display,
     ops::Deref,
+    panic::{RefUnwindSafe, UnwindSafe},
     sync::atomic::{AtomicUsize, Ordering},
 };
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
 use alloc::boxed::Box;
-use core::fmt::Debug;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use core::task::{Context, Poll};
+use core::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+};
 
 #[derive(Debug)]
+#[non_exhaustive]
 /// # `OnceInitError`
 /// 读取或初始化 [`OnceInit`] 内部数据时可能返回该错误。
+///
+/// 以后可能增加新的错误，因此在 crate 外匹配时须包含通配分支。
 pub enum OnceInitError {
     /// 数据未被初始化。
     DataUninitialized,
     /// 数据已被初始化。
     DataInitialized,
+    /// 等待初始化超时。
+    TimedOut,
 }
 
 impl Display for OnceInitError {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let msg = match self {
+            OnceInitError::DataUninitialized => "data is uninitialized.",
+            OnceInitError::DataInitialized => "data has already been initialized.",
+            OnceInitError::TimedOut => "timed out waiting for initialization.",
+        };
+        #[cfg(feature = "locale")]
+        let msg = locale::localized_message(self).unwrap_or(msg);
+        f.write_str(msg)
+    }
+}
+impl Error for OnceInitError {}
+impl OnceInitError {
+    /// 返回该错误对应的错误码，供 ffi 使用，见 [`extern_accessors`].
+    ///
+    /// 错误码是稳定的：`0` 表示成功，不会被任何错误使用；
+    /// [`DataUninitialized`](Self::DataUninitialized) 为 `1`;
+    /// [`DataInitialized`](Self::DataInitialized) 为 `2`;
+    /// [`TimedOut`](Self::TimedOut) 为 `3`.
+    pub const fn code(&self) -> i32 {
         match self {
-            OnceInitError::DataUninitialized {} => f.write_str("data is uninitialized."),
-            OnceInitError::DataInitialized {} => f.write_str("data has already been initialized."),
+            OnceInitError::DataUninitialized => 1,
+            OnceInitError::DataInitialized => 2,
+            OnceInitError::TimedOut => 3,
         }
     }
 }
-impl Error for OnceInitError {}
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[repr(usize)]
+#[non_exhaustive]
 /// # `OnceInitState`
 /// 表示 [`OnceInit`] 内部数据的初始化状态。
+///
+/// 以后可能增加新的状态，因此在 crate 外匹配时须包含通配分支。
 pub enum OnceInitState {
     /// 数据未被初始化。
     UNINITIALIZED = 0,
+    /// 数据正在初始化。
+    ///
+    /// [`OnceInit::state`] 会等待初始化完成，因此不会返回该值；
+    /// [`OnceInit::init_boxed_reporting`] 会以该值报告其他线程正在进行的初始化。
+    INITIALIZING = 1,
     /// 数据已被初始化。
     INITIALIZED = 2,
 }
 
-const UNINITIALIZED: usize = 0;
-const INITIALIZING: usize = 1;
-const INITIALIZED: usize = 2;
+impl OnceInitState {
+    /// 格式化未初始化或正在初始化的 [`OnceInit`] 时使用的占位符。
+    pub(crate) const fn placeholder(self) -> &'static str {
+        match self {
+            OnceInitState::INITIALIZING => "<initializing>",
+            _ => "<uninit>",
+        }
+    }
+}
+impl Display for OnceInitState {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            OnceInitState::UNINITIALIZED => f.write_str("uninitialized"),
+            OnceInitState::INITIALIZING => f.write_str("initializing"),
+            OnceInitState::INITIALIZED => f.write_str("initialized"),
+        }
+    }
+}
+
+/// [`OnceInit::get_or_init_recover`] 调用初始化函数的最多次数。
+#[cfg(not(feature = "no_std"))]
+pub const RECOVER_ATTEMPTS: u32 = 3;
+
+/// `state` 的取值：数据未被初始化。见 [`OnceInit::as_raw`].
+pub const UNINITIALIZED: usize = 0;
+/// `state` 的取值：数据正在初始化。见 [`OnceInit::as_raw`].
+pub const INITIALIZING: usize = 1;
+/// `state` 的取值：数据已被初始化。见 [`OnceInit::as_raw`].
+pub const INITIALIZED: usize = 2;
+
+/// 初始化函数恐慌时，将状态重置为 `UNINITIALIZED`, 使得之后可以重新初始化。
+///
+/// 正常返回时会被 [`forget`](core::mem::forget), 不会执行。
+struct ResetOnUnwind<'a, T: ?Sized + 'static>(&'a OnceInit<T>);
+
+impl<T: ?Sized> Drop for ResetOnUnwind<'_, T> {
+    fn drop(&mut self) {
+        self.0.publish(UNINITIALIZED);
+    }
+}
 
 /// # `OnceInit`
 /// 仅可设置一次数据的类型。
@@ -79,6 +232,20 @@ const INITIALIZED: usize = 2;
 /// 当 `T` 实现了 [`Sync`] 时，该类型也会实现 [`Sync`].
 /// [`Sync`] 是由内部原子类型的 `state` 和外部 api 共同保证的。
 /// 外部 api 保证，当 `state` 指示数据正在或已经初始化时，该类型不可变。
+///
+/// ## 内存布局
+///
+/// 该类型为 `#[repr(C)]`, 字段依次为：
+///
+/// - `state`: 与 `usize` 布局相同的原子整数，取值见 [`UNINITIALIZED`], [`INITIALIZING`] 与 [`INITIALIZED`];
+/// - `data`: 与 `*const T` 布局相同的指针，未初始化时为空。
+///
+/// 因此当 `T: Sized` 时，可以在 C 中以 `struct { uintptr_t state; const T *data; }` 描述该类型。
+/// 通常更推荐使用 [`extern_accessors`] 生成访问函数，而非直接访问字段。
+///
+/// 由于 `data` 按指针对齐，即使将 `state` 换为更小的整数类型，结构体大小也不会减小，
+/// 因此 `state` 保持为 `usize`, 整个类型的大小为 `state` 与 `&'static T` 之和。
+#[repr(C)]
 pub struct OnceInit<T: ?Sized + 'static>
 where
     &'static T: Sized,
@@ -88,16 +255,40 @@ where
 }
 
 impl<T: ?Sized> OnceInit<T> {
-    pub const DEFAULT: Self = Self {
+    /// 未初始化的 [`OnceInit`], 同 [`uninit`](Self::uninit), 可用于静态变量或数组的初始化（如 `[OnceInit::UNINIT; N]`）.
+    ///
+    /// 注意与 [`Default`] 区分：`T: StaticDefault` 时 [`Default::default`] 返回以 [`StaticDefault::static_default`] 初始化的 [`OnceInit`], 而该常量总是未初始化的。
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const UNINIT: Self = Self {
         state: AtomicUsize::new(UNINITIALIZED),
         data: UnsafeCell::new(None),
     };
+    /// 同 [`UNINIT`](Self::UNINIT).
+    #[deprecated(since = "0.0.12", note = "use `UNINIT` instead")]
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const DEFAULT: Self = Self::UNINIT;
     /// 返回未初始化的 [`OnceInit`] 类型。
     #[inline]
     pub const fn uninit() -> Self {
-        Self::DEFAULT
+        Self::UNINIT
     }
     /// 返回初始化过的 [`OnceInit`] 类型。
+    ///
+    /// 参数类型为 `&'static T`, 是强制转换位置：只要 `T` 已由上下文确定（如静态变量的类型），
+    /// 具体类型的引用会自动转换为特型对象或切片的引用，无需 `as` 转换。[`init`](Self::init) 与
+    /// [`init_boxed`](Self::init_boxed) 同理。若改为接受 `impl Into<&'static T>`, 反而会失去这种转换。
+    ///
+    /// ```rust
+    /// use onceinit::OnceInit;
+    /// use std::fmt::Display;
+    ///
+    /// static NAME: OnceInit<dyn Display + Sync> = OnceInit::new(&"app");
+    /// static PORTS: OnceInit<[u16]> = OnceInit::new(&[80, 443]);
+    /// assert_eq!(NAME.get().unwrap().to_string(), "app");
+    /// assert_eq!(PORTS.get().unwrap(), [80, 443]);
+    /// ```
+    ///
+    /// 上下文无法确定 `T` 时，可使用类型标注或 `OnceInit::<dyn Trait>::new`; 已有的 [`OnceInit`] 可用 [`erase`](Self::erase) 转换。
     #[inline]
     pub const fn new(data: &'static T) -> Self
     where
@@ -112,17 +303,95 @@ impl<T: ?Sized> OnceInit<T> {
     /// 返回内部数据，若未初始化，则返回 [`OnceInitError`].
     ///
     /// 若需要可变数据，请在内部使用具有内部可见性的数据结构，如 [`Mutex`](std::sync::Mutex) 等。
-    #[inline]
+    #[inline(always)]
     pub fn get(&self) -> Result<&'static T, OnceInitError> {
-        match self.state.load(Ordering::Acquire) {
+        if self.state.load(Ordering::Acquire) == INITIALIZED {
+            Ok(unsafe { (*self.data.get()).unwrap_unchecked() })
+        } else {
+            self.get_cold()
+        }
+    }
+    /// [`get`](Self::get) 中未初始化或正在初始化的分支。
+    ///
+    /// 单独放在不内联的函数中，以免调用处的快速路径因等待与错误处理而膨胀。
+    #[cold]
+    #[inline(never)]
+    fn get_cold(&self) -> Result<&'static T, OnceInitError> {
+        match self.wait_initializing() {
             INITIALIZED => Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
-            INITIALIZING => {
-                while self.state.load(Ordering::SeqCst) == INITIALIZING {
+            _ => Err(OnceInitError::DataUninitialized),
+        }
+    }
+    /// 返回内部数据，若未初始化，则返回 [`OnceInitError::DataUninitialized`].
+    ///
+    /// 与 [`get`](Self::get) 不同，若数据正在初始化，至多等待 `dur`, 超时则返回 [`OnceInitError::TimedOut`].
+    ///
+    /// 先短暂自旋，之后挂起（[`park_timeout`](std::thread::park_timeout)）当前线程直到初始化结束或超时，
+    /// 不会在等待较慢的初始化时占满一个核心。每次挂起前只读取一次时钟。
+    #[cfg(not(feature = "no_std"))]
+    pub fn with_timeout(&self, dur: std::time::Duration) -> Result<&'static T, OnceInitError> {
+        /// 挂起前自旋的次数，初始化通常很快，自旋可以避免系统调用。
+        const SPINS: u32 = 64;
+        let deadline = std::time::Instant::now() + dur;
+        let mut spun = 0;
+        let mut waker = None;
+        loop {
+            match self.state.load(Ordering::Acquire) {
+                INITIALIZED => return Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
+                INITIALIZING => {}
+                _ => return Err(OnceInitError::DataUninitialized),
+            }
+            if spun < SPINS {
+                spun += 1;
+                core::hint::spin_loop();
+                continue;
+            }
+            let now = std::time::Instant::now();
+            if now >= deadline {
+                return Err(OnceInitError::TimedOut);
+            }
+            let waker = waker.get_or_insert_with(waiter::thread_waker);
+            waiter::register(self.waiter_key(), waker);
+            // 登记后再次检查，以免错过登记前发生的唤醒。
+            if self.state.load(Ordering::SeqCst) == INITIALIZING {
+                std::thread::park_timeout(deadline - now);
+            }
+            // 超时或虚假唤醒时登记仍在表中。
+            waiter::unregister(self.waiter_key(), waker);
+        }
+    }
+    /// 同 [`get`](Self::get), 但可按调用指定等待正在进行的初始化时的退避策略：
+    /// 先自旋至多 `spins` 次，之后若 `then_yield` 为 `true`, 则每次检查前让出（[`yield_now`](std::thread::yield_now)）当前线程，否则继续自旋。
+    ///
+    /// 初始化通常很快时，`spins` 取几十到几百即可；初始化较慢或线程数多于核心数时，宜取较小的 `spins` 并让出线程。
+    ///
+    /// 启用 `no_std` 特性时无法让出线程，`then_yield` 将被忽略。
+    pub fn get_with_backoff(
+        &self,
+        spins: u32,
+        then_yield: bool,
+    ) -> Result<&'static T, OnceInitError> {
+        let mut spun = 0;
+        loop {
+            match self.state.load(Ordering::Acquire) {
+                INITIALIZED => return Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
+                INITIALIZING => {
+                    if spun < spins {
+                        spun += 1;
+                        core::hint::spin_loop();
+                        continue;
+                    }
+                    #[cfg(not(feature = "no_std"))]
+                    if then_yield {
+                        std::thread::yield_now();
+                        continue;
+                    }
+                    #[cfg(feature = "no_std")]
+                    let _ = then_yield;
                     core::hint::spin_loop()
                 }
-                Ok(unsafe { (*self.data.get()).unwrap_unchecked() })
+                _ => return Err(OnceInitError::DataUninitialized),
             }
-            _ => Err(OnceInitError::DataUninitialized),
         }
     }
     /// 返回内部数据，若未初始化，则返回 `<T as StaticDefault>::static_default()`.
@@ -135,6 +404,133 @@ impl<T: ?Sized> OnceInit<T> {
     {
         self.get().unwrap_or_else(|_| T::static_default())
     }
+    /// 同 [`get_or_default`](Self::get_or_default), 但只进行一次读取，从不等待。
+    ///
+    /// 数据正在初始化时同样返回默认值，因此在初始化期间可能短暂地读到默认值，即使初始化随后成功。
+    /// 适用于不允许阻塞的频繁读取路径。
+    #[inline(always)]
+    pub fn try_get_or_default(&self) -> &'static T
+    where
+        T: StaticDefault,
+    {
+        if self.state.load(Ordering::Acquire) == INITIALIZED {
+            unsafe { (*self.data.get()).unwrap_unchecked() }
+        } else {
+            T::static_default()
+        }
+    }
+    /// 返回内部数据，若未初始化，则以 `f` 的返回值初始化后返回。
+    ///
+    /// 多个线程同时调用时，只有一个线程会调用 `f`, 其余线程等待其完成。
+    #[inline]
+    pub fn get_or_init<F>(&self, f: F) -> &'static T
+    where
+        F: FnOnce() -> &'static T,
+    {
+        if let Ok(data) = self.get() {
+            return data;
+        }
+        // 无论成功与否，返回后数据均已被初始化。
+        let _ = self.init_internal(f);
+        unsafe { self.get_unchecked() }
+    }
+    /// 同 [`get_or_init`](Self::get_or_init), 但 `f` 恐慌时捕获恐慌并重试，
+    /// 至多调用 `f` [`RECOVER_ATTEMPTS`] 次，之后仍然恐慌时继续传播最后一次的恐慌。
+    ///
+    /// [`OnceInit`] 没有“中毒”状态：初始化函数恐慌后状态会被重置为未初始化，之后的调用本就可以重新初始化。
+    /// 该函数只是将偶发的初始化失败限制在本次调用中，而不是使其传播给调用者。
+    /// 每次恐慌仍会经过恐慌钩子（默认会打印信息）。
+    #[cfg(not(feature = "no_std"))]
+    pub fn get_or_init_recover<F>(&self, f: F) -> &'static T
+    where
+        F: Fn() -> &'static T,
+    {
+        let mut attempts = 1;
+        loop {
+            match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.get_or_init(&f))) {
+                Ok(data) => return data,
+                Err(payload) if attempts >= RECOVER_ATTEMPTS => std::panic::resume_unwind(payload),
+                Err(_) => attempts += 1,
+            }
+        }
+    }
+    /// 同 [`get_or_init`](Self::get_or_init), 但从不等待，类似 `once_cell::race`.
+    ///
+    /// 未初始化时总是先调用 `f`, 再尝试发布其返回值。多个线程同时调用时，**`f` 可能被调用多次**：
+    ///
+    /// - 最先发布的值胜出，之后观察到已初始化的线程丢弃自己的值，返回胜出的值；
+    /// - 恰好有其他线程正在写入（或以 [`get_or_init`](Self::get_or_init) 等正在初始化）时，不等待，直接返回自己的值。
+    ///
+    /// 因此只适用于 `f` 开销小、幂等且各次返回的值等价的情形。
+    ///
+    /// 内部数据可能是胖指针，无法原子地写入，因此发布时仍以状态的比较交换取得写入权，
+    /// 但取得失败的线程不会等待写入或初始化完成。
+    #[inline]
+    pub fn get_or_init_racy<F>(&self, f: F) -> &'static T
+    where
+        F: Fn() -> &'static T,
+    {
+        if self.state.load(Ordering::Acquire) == INITIALIZED {
+            return unsafe { self.get_unchecked() };
+        }
+        let data = f();
+        match self.state.compare_exchange(
+            UNINITIALIZED,
+            INITIALIZING,
+            Ordering::Acquire,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => {
+                unsafe { *self.data.get() = Some(data) }
+                self.publish(INITIALIZED);
+                data
+            }
+            Err(INITIALIZED) => unsafe { self.get_unchecked() },
+            Err(_) => data,
+        }
+    }
+    /// 返回内部数据，若未初始化，则以 `f` 的返回值初始化后返回。
+    ///
+    /// `f` 返回错误时原样返回该错误，数据保持未初始化，之后可以再次尝试。
+    /// 多个线程同时调用时，只有一个线程会调用 `f`, 其余线程等待其完成；若其失败，则其余线程中的一个会再次尝试。
+    #[inline]
+    pub fn get_or_try_init<E, F>(&self, f: F) -> Result<&'static T, E>
+    where
+        F: FnOnce() -> Result<&'static T, E>,
+    {
+        if let Ok(data) = self.get() {
+            return Ok(data);
+        }
+        if let Ok(Err(e)) = self.try_init_reporting_internal(f) {
+            return Err(e);
+        }
+        Ok(unsafe { self.get_unchecked() })
+    }
+    /// 若未初始化，则以 `data` 初始化。返回最终的内部数据，以及本次调用是否完成了初始化。
+    ///
+    /// 若其他线程正在初始化，则等待其完成。
+    #[inline]
+    pub fn get_or_set(&self, data: &'static T) -> (&'static T, bool) {
+        let set = self.init(data).is_ok();
+        (unsafe { self.get_unchecked() }, set)
+    }
+    /// 同 [`get_or_set`](Self::get_or_set), 但不返回本次调用是否完成了初始化：
+    /// 初始化成功时返回 `data`, 否则返回其他线程设置的数据。
+    #[inline]
+    pub fn init_or_get(&self, data: &'static T) -> &'static T {
+        self.get_or_set(data).0
+    }
+    /// 返回内部数据，若未初始化，则以 `f` 返回的 [`Box`] 初始化后返回。
+    ///
+    /// 只有成功初始化的线程会调用 `f` 并泄漏其返回的 [`Box`], 因此至多泄漏一次。
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn get_or_init_boxed<F>(&self, f: F) -> &'static T
+    where
+        F: FnOnce() -> Box<T>,
+    {
+        self.get_or_init(|| Box::leak(f()))
+    }
     /// 不检查是否初始化，直接返回内部数据。
     ///
     /// 若需要可变数据，请在内部使用具有内部可见性的数据结构，如 [`Mutex`](std::sync::Mutex) 等。
@@ -144,48 +540,384 @@ impl<T: ?Sized> OnceInit<T> {
     /// 未初始化时，调用此函数会在内部的 [`None`] 值上调用 [`Option::unwrap_unchecked`], 造成[*未定义行为*]。
     ///
     /// [*未定义行为*]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
-    #[inline]
+    #[inline(always)]
     pub unsafe fn get_unchecked(&self) -> &'static T {
         unsafe { (*self.data.get()).unwrap_unchecked() }
     }
+    /// 返回数据是否已被初始化。
+    ///
+    /// 只进行一次读取，不会等待正在进行的初始化，此时返回 `false`.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self.state.load(Ordering::Acquire) == INITIALIZED
+    }
+    /// 返回两个 [`OnceInit`] 内部存储的是否为同一地址的数据，均未初始化时返回 `true`.
+    ///
+    /// 只比较地址，不要求 `T: PartialEq`, 适用于特型对象等类型。正在初始化时会等待其完成。
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (self.get(), other.get()) {
+            (Ok(a), Ok(b)) => core::ptr::addr_eq(a, b),
+            (Err(_), Err(_)) => true,
+            _ => false,
+        }
+    }
+    /// 返回数据是否已初始化且与 `value` 相等，未初始化时总是返回 `false`.
+    ///
+    /// 正在初始化时会等待其完成。与 `==` 等价，但也适用于 `str` 等动态大小类型。
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.get().is_ok_and(|data| data == value)
+    }
+    /// 返回 `self` 的内部数据，若未初始化，则返回 `other` 的内部数据；二者均未初始化时返回
+    /// [`OnceInitError::DataUninitialized`].
+    ///
+    /// 适用于主、备两层配置。不会修改任何一方，正在初始化时会等待其完成。
+    #[inline]
+    pub fn or(&self, other: &OnceInit<T>) -> Result<&'static T, OnceInitError> {
+        self.get().or_else(|_| other.get())
+    }
+    /// 返回解引用一层后的内部数据，如由 `OnceInit<String>` 得到 `&'static str`.
+    ///
+    /// 若未初始化，则返回 [`OnceInitError`]. 正在初始化时会等待其完成。
+    #[inline]
+    pub fn as_deref(&self) -> Result<&'static T::Target, OnceInitError>
+    where
+        T: Deref,
+        T::Target: 'static,
+    {
+        self.get().map(|data| &**data)
+    }
+    /// 返回固定（pinned）的内部数据，若未初始化，则返回 [`OnceInitError`]. 正在初始化时会等待其完成。
+    ///
+    /// 内部数据是 `&'static T`: 被引用的值在程序运行期间一直存在，且不会被移动或释放
+    /// （[`OnceInit`] 从不拥有数据，[`take`](Self::take) 也只是取出引用）, 因此总可以固定，
+    /// 见 [`Pin::static_ref`](core::pin::Pin::static_ref). 适用于 `!Unpin` 的自引用或侵入式链表等全局数据。
+    #[inline]
+    pub fn get_pinned(&self) -> Result<core::pin::Pin<&'static T>, OnceInitError> {
+        self.get().map(core::pin::Pin::static_ref)
+    }
+    /// 返回产生 0 或 1 个元素的迭代器：已初始化时产生内部数据，否则不产生任何元素。正在初始化时会等待其完成。
+    ///
+    /// 同 [`Option::into_iter`], 可用于将多个可能未初始化的全局数据串联为一个迭代器。
+    /// 由于 `&OnceInit<[T]>` 的 [`IntoIterator`] 逐个产生切片元素，该功能以方法而非 [`IntoIterator`] 的形式提供。
+    ///
+    /// ``` rust
+    /// use onceinit::OnceInit;
+    ///
+    /// static PRIMARY: OnceInit<str> = OnceInit::new("primary");
+    /// static FALLBACK: OnceInit<str> = OnceInit::uninit();
+    /// let names: Vec<_> = PRIMARY.iter_opt().chain(FALLBACK.iter_opt()).collect();
+    /// assert_eq!(names, ["primary"]);
+    /// ```
+    #[inline]
+    pub fn iter_opt(&self) -> core::option::IntoIter<&'static T> {
+        self.get().ok().into_iter()
+    }
+    /// 以 `f` 转换内部数据，若未初始化，则返回 [`OnceInitError`]. 同 [`Result::map`].
+    #[inline]
+    pub fn map<U, F>(&self, f: F) -> Result<U, OnceInitError>
+    where
+        F: FnOnce(&'static T) -> U,
+    {
+        self.get().map(f)
+    }
+    /// 以 `f` 转换内部数据，`f` 可以失败；若未初始化，则返回 [`OnceInitError`]. 同 [`Result::and_then`].
+    #[inline]
+    pub fn and_then<U, F>(&self, f: F) -> Result<U, OnceInitError>
+    where
+        F: FnOnce(&'static T) -> Result<U, OnceInitError>,
+    {
+        self.get().and_then(f)
+    }
+    /// 以 `f` 转换内部数据，若未初始化，则返回 `default`. 同 [`Result::map_or`].
+    ///
+    /// `default` 总会被求值，开销较大时请使用 [`map_or_else`](Self::map_or_else).
+    #[inline]
+    pub fn map_or<U, F>(&self, default: U, f: F) -> U
+    where
+        F: FnOnce(&'static T) -> U,
+    {
+        self.get().map_or(default, f)
+    }
+    /// 以 `f` 转换内部数据，若未初始化，则返回 `default()` 的值。`default` 仅在未初始化时调用。
+    #[inline]
+    pub fn map_or_else<U, D, F>(&self, default: D, f: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(&'static T) -> U,
+    {
+        self.get().map_or_else(|_| default(), f)
+    }
+    /// 以可能失败的 `f` 转换内部数据。
+    ///
+    /// - 未初始化时返回 `Ok(None)`;
+    /// - 已初始化且 `f` 成功时返回 `Ok(Some(u))`;
+    /// - 已初始化且 `f` 失败时返回 `Err(e)`.
+    ///
+    /// 需要将未初始化也视作错误时，见 [`try_map_flat`](Self::try_map_flat).
+    #[inline]
+    pub fn try_map<U, E, F>(&self, f: F) -> Result<Option<U>, E>
+    where
+        F: FnOnce(&'static T) -> Result<U, E>,
+    {
+        self.get().ok().map(f).transpose()
+    }
+    /// 同 [`try_map`](Self::try_map), 但未初始化时返回 `Err(uninit)`.
+    #[inline]
+    pub fn try_map_flat<U, E, F>(&self, uninit: E, f: F) -> Result<U, E>
+    where
+        F: FnOnce(&'static T) -> Result<U, E>,
+    {
+        self.get().map_or(Err(uninit), f)
+    }
+    /// 以当前数据构造一个新的 [`OnceInit<U>`], 通常用于将具体类型转换为特型对象。
+    ///
+    /// 稳定版 Rust 无法对泛型参数进行非固定大小转换（unsizing coercion）, 因此需传入形如
+    /// `|data| data` 的闭包，并通过泛型参数指定目标类型，由编译器完成转换。
+    ///
+    /// 返回的是当前状态的快照：未初始化时返回未初始化的 [`OnceInit`], 此后对 `self` 的初始化不会反映到其上。
+    /// 正在初始化时会等待其完成。
+    ///
+    /// ```rust
+    /// use onceinit::OnceInit;
+    /// use std::fmt::Display;
+    ///
+    /// static NUMBER: OnceInit<u32> = OnceInit::new(&42);
+    /// let display = NUMBER.erase::<dyn Display + Sync>(|data| data);
+    /// assert_eq!(display.get().unwrap().to_string(), "42");
+    /// ```
+    #[inline]
+    pub fn erase<U: ?Sized>(&self, coerce: impl FnOnce(&'static T) -> &'static U) -> OnceInit<U> {
+        match self.get() {
+            Ok(data) => OnceInit::new(coerce(data)),
+            Err(_) => OnceInit::uninit(),
+        }
+    }
+    /// 取出内部数据，并将 [`OnceInit`] 重置为未初始化，之后可以重新初始化。未初始化时返回 [`None`]. 同 [`Option::take`].
+    ///
+    /// 由于需要 `&mut self`, 不会与其他读写操作竞争，因此是安全的。
+    /// [`OnceInit`] 从不拥有数据，之前返回的 `&'static T` 在此之后依然有效。
+    #[inline]
+    pub fn take(&mut self) -> Option<&'static T> {
+        *self.state.get_mut() = UNINITIALIZED;
+        self.data.get_mut().take()
+    }
     /// 返回数据状态，见 [`OnceInitState`].
     pub fn state(&self) -> OnceInitState {
         match self.state.load(Ordering::Acquire) {
             UNINITIALIZED => OnceInitState::UNINITIALIZED,
-            INITIALIZING => {
-                while self.state.load(Ordering::SeqCst) == INITIALIZING {
-                    core::hint::spin_loop()
-                }
-                OnceInitState::UNINITIALIZED
-            }
+            INITIALIZING => match self.wait_initializing() {
+                INITIALIZED => OnceInitState::INITIALIZED,
+                _ => OnceInitState::UNINITIALIZED,
+            },
             INITIALIZED => OnceInitState::INITIALIZED,
             _ => unreachable!(),
         }
     }
+    /// 返回描述当前状态的字符串：`"uninitialized"`, `"initializing"` 或 `"initialized"`, 适用于日志。
+    ///
+    /// 只读取一次状态，不会等待，也不会访问内部数据。
+    #[inline]
+    pub fn debug_state_str(&self) -> &'static str {
+        match self.state.load(Ordering::Acquire) {
+            UNINITIALIZED => "uninitialized",
+            INITIALIZING => "initializing",
+            _ => "initialized",
+        }
+    }
+    /// 断言数据未被初始化，否则以 `msg` 恐慌。
+    ///
+    /// 数据正在初始化时同样会恐慌，不会等待。可在初始化前调用，以尽早发现意外的提前初始化。
+    #[inline]
+    #[track_caller]
+    pub fn expect_uninit(&self, msg: &str) {
+        if self.state.load(Ordering::Acquire) != UNINITIALIZED {
+            panic!("{msg}")
+        }
+    }
+    /// 只读取一次状态，不等待。未初始化或正在初始化时返回对应的状态，用于格式化输出等不应阻塞的场合。
+    #[inline]
+    pub(crate) fn peek(&self) -> Result<&'static T, OnceInitState> {
+        match self.state.load(Ordering::Acquire) {
+            INITIALIZED => Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
+            INITIALIZING => Err(OnceInitState::INITIALIZING),
+            _ => Err(OnceInitState::UNINITIALIZED),
+        }
+    }
+    /// 等待正在进行的初始化结束，返回结束后的状态。
+    ///
+    /// 初始化函数发生恐慌时，状态会被重置为 `UNINITIALIZED`, 因此返回值不一定是 `INITIALIZED`.
+    fn wait_initializing(&self) -> usize {
+        loop {
+            match self.state.load(Ordering::SeqCst) {
+                #[cfg(not(feature = "atomic-wait"))]
+                INITIALIZING => core::hint::spin_loop(),
+                #[cfg(feature = "atomic-wait")]
+                INITIALIZING => os_wait::wait(|| self.state.load(Ordering::SeqCst) == INITIALIZING),
+                state => return state,
+            }
+        }
+    }
+    /// 结束初始化，将状态设置为 `state`, 并唤醒等待初始化结束的任务。
+    fn publish(&self, state: usize) {
+        self.state.store(state, Ordering::SeqCst);
+        #[cfg(feature = "atomic-wait")]
+        os_wait::wake_all();
+        #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+        waiter::wake_all(self.waiter_key());
+    }
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    fn waiter_key(&self) -> usize {
+        self as *const Self as *const () as usize
+    }
+    /// 尝试获取内部数据，可用于手动实现异步等待。
+    ///
+    /// - 已初始化时，返回 `Poll::Ready(Ok(..))`;
+    /// - 未初始化时，返回 `Poll::Ready(Err(OnceInitError::DataUninitialized))`;
+    /// - 正在初始化时，返回 [`Poll::Pending`], 并在初始化结束（成功或失败）时唤醒 `cx` 中的 [`Waker`](core::task::Waker).
+    ///
+    /// 登记的 [`Waker`](core::task::Waker) 只在初始化结束时移出登记表。若可能在 [`Poll::Pending`] 后放弃等待，
+    /// 请使用 [`wait`](Self::wait) 返回的 [`Future`](core::future::Future), 它被丢弃时会取消登记。
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn poll(&self, cx: &mut Context<'_>) -> Poll<Result<&'static T, OnceInitError>> {
+        if self.state.load(Ordering::Acquire) == INITIALIZING {
+            waiter::register(self.waiter_key(), cx.waker());
+            // 注册后再次检查，以免错过注册前发生的唤醒。
+            if self.state.load(Ordering::SeqCst) == INITIALIZING {
+                return Poll::Pending;
+            }
+            waiter::unregister(self.waiter_key(), cx.waker());
+        }
+        Poll::Ready(match self.state.load(Ordering::Acquire) {
+            INITIALIZED => Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
+            _ => Err(OnceInitError::DataUninitialized),
+        })
+    }
+    /// 尝试获取内部数据，可用于在不依赖特定运行时的情况下实现异步等待。
+    ///
+    /// 与 [`poll`](Self::poll) 不同，未初始化时同样返回 [`Poll::Pending`], 并在数据初始化完成时唤醒 `cx` 中的
+    /// [`Waker`](core::task::Waker), 因此不依赖 `std`, 可用于 `no_std` 环境下的执行器（需启用 `alloc` 特性）。
+    ///
+    /// 初始化失败（初始化函数恐慌）时同样会唤醒，此时再次调用将重新登记。
+    ///
+    /// 同 [`poll`](Self::poll), 放弃等待时登记项会一直保留到初始化结束，此时请使用 [`wait`](Self::wait).
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn poll_get(&self, cx: &mut Context<'_>) -> Poll<&'static T> {
+        if self.state.load(Ordering::Acquire) != INITIALIZED {
+            waiter::register(self.waiter_key(), cx.waker());
+            // 注册后再次检查，以免错过注册前发生的唤醒。
+            if self.state.load(Ordering::SeqCst) != INITIALIZED {
+                return Poll::Pending;
+            }
+            waiter::unregister(self.waiter_key(), cx.waker());
+        }
+        Poll::Ready(unsafe { (*self.data.get()).unwrap_unchecked() })
+    }
+    /// 阻塞当前线程，直到数据被初始化，然后返回内部数据。
+    ///
+    /// 与 [`get`](Self::get) 不同，未初始化时不会返回错误，而是挂起（[`park`](std::thread::park)）当前线程，
+    /// 等待其他线程完成初始化。若始终没有线程初始化数据，该函数将永远不会返回。
+    #[cfg(not(feature = "no_std"))]
+    pub fn wait_initialized(&self) -> &'static T {
+        let waker = waiter::thread_waker();
+        loop {
+            if self.state.load(Ordering::Acquire) == INITIALIZED {
+                return unsafe { (*self.data.get()).unwrap_unchecked() };
+            }
+            waiter::register(self.waiter_key(), &waker);
+            if self.state.load(Ordering::SeqCst) == INITIALIZED {
+                waiter::unregister(self.waiter_key(), &waker);
+                continue;
+            }
+            std::thread::park();
+        }
+    }
+    /// 同 [`wait_initialized`](Self::wait_initialized), 但至多阻塞到 `deadline`, 超时则返回 [`OnceInitError::TimedOut`].
+    ///
+    /// 适用于多个等待共用同一截止时间的情形。`deadline` 已过时只检查一次，不会阻塞。
+    #[cfg(not(feature = "no_std"))]
+    pub fn get_until(&self, deadline: std::time::Instant) -> Result<&'static T, OnceInitError> {
+        let waker = waiter::thread_waker();
+        loop {
+            if self.state.load(Ordering::Acquire) == INITIALIZED {
+                return Ok(unsafe { (*self.data.get()).unwrap_unchecked() });
+            }
+            let now = std::time::Instant::now();
+            if now >= deadline {
+                return Err(OnceInitError::TimedOut);
+            }
+            waiter::register(self.waiter_key(), &waker);
+            if self.state.load(Ordering::SeqCst) == INITIALIZED {
+                waiter::unregister(self.waiter_key(), &waker);
+                continue;
+            }
+            std::thread::park_timeout(deadline - now);
+            // 超时醒来时登记仍在表中。
+            waiter::unregister(self.waiter_key(), &waker);
+        }
+    }
     fn init_internal<F>(&self, make_data: F) -> Result<(), OnceInitError>
     where
         F: FnOnce() -> &'static T,
     {
-        let old_state = match self.state.compare_exchange(
-            UNINITIALIZED,
-            INITIALIZING,
-            Ordering::SeqCst,
-            Ordering::SeqCst,
-        ) {
-            Ok(s) | Err(s) => s,
-        };
-        match old_state {
-            INITIALIZING => {
-                while self.state.load(Ordering::SeqCst) == INITIALIZING {
-                    core::hint::spin_loop()
+        self.init_reporting_internal(make_data)
+            .map_err(|_| OnceInitError::DataInitialized)
+    }
+    /// 同 `init_internal`, 失败时返回尝试初始化时观察到的状态，
+    /// 即 `INITIALIZING`（等待其他线程完成了初始化）或 `INITIALIZED`.
+    fn init_reporting_internal<F>(&self, make_data: F) -> Result<(), OnceInitState>
+    where
+        F: FnOnce() -> &'static T,
+    {
+        self.try_init_reporting_internal(|| Ok::<_, core::convert::Infallible>(make_data()))
+            .map(|r| match r {
+                Ok(()) => (),
+                Err(e) => match e {},
+            })
+    }
+    /// 同 `init_reporting_internal`, 但 `make_data` 可能失败。
+    ///
+    /// `make_data` 返回错误时，状态会被重置为 `UNINITIALIZED`, 并返回 `Ok(Err(e))`.
+    fn try_init_reporting_internal<E, F>(
+        &self,
+        make_data: F,
+    ) -> Result<Result<(), E>, OnceInitState>
+    where
+        F: FnOnce() -> Result<&'static T, E>,
+    {
+        loop {
+            let old_state = match self.state.compare_exchange(
+                UNINITIALIZED,
+                INITIALIZING,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            ) {
+                Ok(s) | Err(s) => s,
+            };
+            match old_state {
+                INITIALIZING => {
+                    // 若正在进行的初始化失败（恐慌），则重新尝试。
+                    if self.wait_initializing() == INITIALIZED {
+                        return Err(OnceInitState::INITIALIZING);
+                    }
+                }
+                INITIALIZED => return Err(OnceInitState::INITIALIZED),
+                _ => {
+                    let guard = ResetOnUnwind(self);
+                    // 返回错误时同样由 `guard` 重置状态。
+                    let data = match make_data() {
+                        Ok(data) => data,
+                        Err(e) => return Ok(Err(e)),
+                    };
+                    core::mem::forget(guard);
+                    unsafe { *self.data.get() = Some(data) }
+                    self.publish(INITIALIZED);
+                    return Ok(Ok(()));
                 }
-                Err(OnceInitError::DataInitialized)
-            }
-            INITIALIZED => Err(OnceInitError::DataInitialized),
-            _ => {
-                unsafe { *self.data.get() = Some(make_data()) }
-                self.state.store(INITIALIZED, Ordering::SeqCst);
-                Ok(())
             }
         }
     }
@@ -196,34 +928,276 @@ impl<T: ?Sized> OnceInit<T> {
     pub fn init(&self, data: &'static T) -> Result<(), OnceInitError> {
         self.init_internal(|| data)
     }
+    /// 校验 `data` 后以其初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    ///
+    /// 错误类型 `E` 由调用者决定：`check` 返回的错误原样返回，已初始化等情形下的 [`OnceInitError`]
+    /// 则经由 [`From`] 转换为 `E`. 只有成功初始化的线程会调用 `check`, 校验失败时数据保持未初始化。
+    #[inline]
+    pub fn init_checked<E, F>(&self, data: &'static T, check: F) -> Result<(), E>
+    where
+        E: From<OnceInitError>,
+        F: FnOnce(&'static T) -> Result<(), E>,
+    {
+        match self.try_init_reporting_internal(|| check(data).map(|_| data)) {
+            Ok(result) => result,
+            Err(_) => Err(OnceInitError::DataInitialized.into()),
+        }
+    }
+    /// 以 `f` 的返回值初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    ///
+    /// `f` 在当前线程中立即调用，但只有成功初始化的线程会调用 `f`: 已初始化或其他线程正在初始化时，
+    /// `f` 不会被调用。`f` 恐慌时状态会被重置为未初始化。
+    #[inline]
+    pub fn init_fn<F>(&self, f: F) -> Result<(), OnceInitError>
+    where
+        F: FnOnce() -> &'static T,
+    {
+        self.init_internal(f)
+    }
     /// 初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
     #[inline]
     #[cfg(any(feature = "alloc", not(feature = "no_std")))]
     pub fn init_boxed(&self, data: Box<T>) -> Result<(), OnceInitError> {
         self.init_internal(|| Box::leak(data))
     }
+    /// 以 `f` 返回的 [`Box`] 初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    ///
+    /// 与 [`init_boxed`](Self::init_boxed) 不同，只有成功初始化的线程会调用 `f`,
+    /// 因此多个线程竞争初始化时，失败的线程不会进行任何分配。
+    #[inline]
+    #[doc(alias("init_boxed_once", "leak_once_from_fn"))]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_boxed_with<F>(&self, f: F) -> Result<(), OnceInitError>
+    where
+        F: FnOnce() -> Box<T>,
+    {
+        self.init_internal(|| Box::leak(f()))
+    }
+    /// 初始化内部数据，只可调用一次。
+    ///
+    /// 成功则返回泄漏后的数据引用；失败则原样返回 `data`, 不会将其泄漏或丢弃。
+    ///
+    /// 如需与 [`init_boxed`](Self::init_boxed) 相同的返回值，可使用
+    /// `.map(|_| ()).map_err(|_| OnceInitError::DataInitialized)`.
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn try_init_boxed_take(&self, data: Box<T>) -> Result<&'static T, Box<T>> {
+        let mut data = Some(data);
+        match self.init_internal(|| Box::leak(data.take().unwrap())) {
+            Ok(()) => Ok(unsafe { self.get_unchecked() }),
+            Err(_) => Err(data.unwrap()),
+        }
+    }
+    /// 尝试以 `data` 初始化内部数据，返回尝试前的状态：
+    ///
+    /// - [`OnceInitState::UNINITIALIZED`]: 本次调用完成了初始化，此时不返回 `data`;
+    /// - [`OnceInitState::INITIALIZING`]: 其他线程正在初始化，等待其完成后，原样返回 `data`;
+    /// - [`OnceInitState::INITIALIZED`]: 数据早已被初始化，原样返回 `data`.
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_boxed_reporting(&self, data: Box<T>) -> (OnceInitState, Option<Box<T>>) {
+        let mut data = Some(data);
+        match self.init_reporting_internal(|| Box::leak(data.take().unwrap())) {
+            Ok(()) => (OnceInitState::UNINITIALIZED, None),
+            Err(state) => (state, data),
+        }
+    }
+    /// 无论当前状态如何，都以 `data` 覆盖内部数据，调用后数据处于已初始化状态。
+    ///
+    /// 该函数是为热重载等需要替换全局数据的场景准备的，**不是**通用的 api, 请谨慎使用。
+    ///
+    /// 之前通过 [`get`](Self::get) 等函数取得的 `&'static T` 依然有效，但指向的是旧数据。
+    ///
+    /// # Safety
+    ///
+    /// 调用期间不得有其他线程读取该 [`OnceInit`] 的内部数据（如调用 [`get`](Self::get) 或解引用），
+    /// 否则会造成数据竞争。调用者需自行保证此时各线程处于静默状态。
+    #[inline]
+    pub unsafe fn init_overwrite(&self, data: &'static T) {
+        // 与其他写入者互斥：若其他线程正在初始化，则等待其完成。
+        while self.state.swap(INITIALIZING, Ordering::SeqCst) == INITIALIZING {
+            core::hint::spin_loop()
+        }
+        unsafe { *self.data.get() = Some(data) }
+        self.publish(INITIALIZED);
+    }
+    /// 以裸指针初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    ///
+    /// 适用于数据由外部（如 C 宿主程序）移交的情形。`T` 可以是动态大小类型，此时 `ptr` 为胖指针。
+    ///
+    /// # Safety
+    ///
+    /// `ptr` 必须非空、对齐且指向有效的 `T`, 并且在程序剩余的运行期间始终有效、可被共享访问，
+    /// 即指向的数据不会被释放，也不会被以可变方式访问。
+    #[inline]
+    pub unsafe fn init_raw(&self, ptr: *const T) -> Result<(), OnceInitError> {
+        self.init_internal(|| unsafe { &*ptr })
+    }
 }
+impl<T> OnceInit<T> {
+    /// 返回内部数据，若未初始化，则以 `Box::new(T::default())` 初始化后返回。
+    ///
+    /// 与在 [`StaticDefault`] 中使用 [`Box::leak`] 不同，该函数经由与 [`get_or_init_boxed`](Self::get_or_init_boxed)
+    /// 相同的路径初始化：只有完成初始化的那次调用会构造并泄漏默认值，因此每个 [`OnceInit`] **至多泄漏一次**，
+    /// 之后的调用直接返回已存储的数据。
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_with_boxed_default(&self) -> &'static T
+    where
+        T: Default,
+    {
+        self.get_or_init_boxed(|| Box::new(T::default()))
+    }
+    /// 以泄漏的 `T::default()` 初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    ///
+    /// 与 [`init_with_boxed_default`](Self::init_with_boxed_default) 不同，已初始化时返回错误而非已存储的数据，
+    /// 适用于需要在确定的时机、恰好构造一次默认值的情形。只有成功初始化的线程会构造默认值。
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_default(&self) -> Result<(), OnceInitError>
+    where
+        T: Default,
+    {
+        self.init_boxed_with(|| Box::new(T::default()))
+    }
+    /// 返回指向内部数据的指针，若未初始化，则返回空指针。
+    ///
+    /// 只进行一次 [`Acquire`](Ordering::Acquire) 读取，不会等待正在进行的初始化，此时同样返回空指针。
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        match self.state.load(Ordering::Acquire) {
+            INITIALIZED => unsafe { (*self.data.get()).unwrap_unchecked() },
+            _ => core::ptr::null(),
+        }
+    }
+    /// 返回内部的状态值与数据指针，可用 [`from_raw`](Self::from_raw) 重新构造 [`OnceInit`].
+    ///
+    /// 若数据正在初始化，会等待其完成。因此返回的状态值只可能是 [`UNINITIALIZED`] 或 [`INITIALIZED`],
+    /// 且仅在状态值为 [`INITIALIZED`] 时指针有效，否则指针为空。
+    pub fn as_raw(&self) -> (usize, *const T) {
+        match self.get() {
+            Ok(data) => (INITIALIZED, data),
+            Err(_) => (UNINITIALIZED, core::ptr::null()),
+        }
+    }
+    /// 由状态值与数据指针构造 [`OnceInit`], 通常与 [`as_raw`](Self::as_raw) 配合使用。
+    ///
+    /// # Safety
+    ///
+    /// `state` 须来自 [`as_raw`](Self::as_raw) 的返回值，即只能是 [`UNINITIALIZED`] 或 [`INITIALIZED`];
+    /// 其他值（包括 [`INITIALIZING`]）在调试构建中会触发断言。
+    ///
+    /// `state` 为 [`INITIALIZED`] 时，`ptr` 须满足 [`init_raw`](Self::init_raw) 的要求；
+    /// 为 [`UNINITIALIZED`] 时，`ptr` 会被忽略。
+    pub const unsafe fn from_raw(state: usize, ptr: *const T) -> Self {
+        debug_assert!(
+            state == UNINITIALIZED || state == INITIALIZED,
+            "`state` must come from `OnceInit::as_raw`."
+        );
+        match state {
+            INITIALIZED => Self::new(unsafe { &*ptr }),
+            _ => Self::uninit(),
+        }
+    }
+}
+/// 共享 [`OnceInit`] 只会使其他线程得到 `&'static T`, 因此要求 `T: Sync`.
+///
+/// 不要求 `T: Send`: 包括 [`init_boxed`](OnceInit::init_boxed) 在内，所有初始化方式都只将数据泄漏为 `&'static T`,
+/// 之后数据不会被释放，也没有任何 api 会将其所有权或 `&mut T` 交给其他线程。
+/// 初始化失败时未被使用的 [`Box`] 在调用者线程中释放。
+///
+/// 被接受与被拒绝的组合由 `tests/ui` 中的编译测试固定，可在 Miri 下运行的用例见 `tests/soundness.rs`.
 unsafe impl<T> Sync for OnceInit<T> where T: ?Sized + Sync {}
-impl<T: ?Sized> Default for OnceInit<T>
-where
-    T: Sized + StaticDefault,
-    Self: Sized,
-{
+/// [`OnceInit`] 只持有 `&'static T`, 移动到其他线程相当于将 `&T` 发送到其他线程，因此只要求 `T: Sync`.
+///
+/// 这里刻意没有要求 `T: Send + Sync`: 那样会拒绝 `OnceInit<MutexGuard<..>>` 等本可安全移动的类型，
+/// 并使 `OnceInit<T>` 比 `&'static T` 本身更受限。该约束与自动推导的结果相同，显式实现是为了固定它，
+/// 以免日后改变字段时意外收紧或放宽。
+///
+/// 额外要求 `T: Send` 并不会使其更安全：[`OnceInit`] 从不拥有或释放数据，接收者只能得到 `&'static T`.
+/// 需要在线程间移动非 [`Sync`] 的数据时，可使用拥有数据的 [`SendOnceInit`].
+unsafe impl<T> Send for OnceInit<T> where T: ?Sized + Sync {}
+/// 初始化函数恐慌时状态会被重置为未初始化，内部数据只在初始化完成后才可见，且之后不再改变，
+/// 因此恐慌不会使其他代码观察到不一致的状态。与 [`OnceLock`](std::sync::OnceLock) 相同，只要求 `T` 满足对应的约束。
+impl<T: ?Sized + RefUnwindSafe> RefUnwindSafe for OnceInit<T> {}
+/// 见 [`RefUnwindSafe`] 的实现。
+impl<T: ?Sized + RefUnwindSafe> UnwindSafe for OnceInit<T> {}
+impl<T: StaticDefault> Default for OnceInit<T> {
     #[inline]
     fn default() -> Self {
         Self::new(T::static_default())
     }
 }
+/// 已初始化时输出 `OnceInit(data)`, 否则输出 `OnceInit(<uninit>)` 或 `OnceInit(<initializing>)`.
+///
+/// 只读取一次状态，不会等待正在进行的初始化，因此可以在恐慌钩子等场合安全地使用。
 impl<T: ?Sized + Debug> Debug for OnceInit<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut d = f.debug_tuple("OnceInit");
-        match self.get().ok() {
-            Some(data) => d.field(&data),
-            None => d.field(&format_args!("<uninit>")),
+        match self.peek() {
+            Ok(data) => d.field(&data),
+            Err(state) => d.field(&format_args!("{}", state.placeholder())),
         };
         d.finish()
     }
 }
+/// 复制当前状态的快照：已初始化时返回指向同一数据的 [`OnceInit`], 否则返回未初始化的 [`OnceInit`].
+///
+/// 正在初始化时会等待其完成。[`OnceInit`] 只持有 `'static` 引用，从不拥有（释放）数据，因此复制是安全的。
+impl<T: ?Sized> Clone for OnceInit<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.erase(|data| data)
+    }
+}
+/// 等价于 `cell.get().ok()`: 已初始化时返回 `Some(data)`, 否则返回 [`None`]. 正在初始化时会等待其完成。
+impl<T: ?Sized> From<&OnceInit<T>> for Option<&'static T> {
+    #[inline]
+    fn from(cell: &OnceInit<T>) -> Self {
+        cell.get().ok()
+    }
+}
+/// 已初始化时转发至内部数据，否则输出 `<uninit>` 或 `<initializing>`.
+///
+/// 与 [`Debug`] 相同，不会等待正在进行的初始化。
+/// 未初始化时不会回退到 [`StaticDefault`] 提供的默认值，以便发现意外的未初始化访问。
+impl<T: ?Sized + Display> Display for OnceInit<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.peek() {
+            Ok(data) => Display::fmt(data, f),
+            Err(state) => f.write_str(state.placeholder()),
+        }
+    }
+}
+/// 两个 [`OnceInit`] 相等，当且仅当二者均未初始化，或均已初始化且内部数据相等。
+///
+/// 已初始化与未初始化的 [`OnceInit`] 总是不相等。正在初始化时会等待其完成。
+impl<T: ?Sized + PartialEq> PartialEq for OnceInit<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.get().ok() == other.get().ok()
+    }
+}
+impl<T: ?Sized + Eq> Eq for OnceInit<T> {}
+/// 直接与数据比较，见 [`OnceInit::contains`]. 未初始化的 [`OnceInit`] 与任何值均不相等。
+///
+/// 受孤儿规则限制，无法提供对称的 `impl PartialEq<OnceInit<T>> for T`, 比较时需将 [`OnceInit`] 写在左侧。
+impl<T: ?Sized + PartialEq> PartialEq<T> for OnceInit<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.contains(other)
+    }
+}
+/// 与 [`PartialEq`] 一致：未初始化时视作 [`None`], 已初始化时视作 `Some(data)`.
+///
+/// 因此初始化前后的哈希值不同。将未初始化的 [`OnceInit`] 放入 [`HashSet`](std::collections::HashSet)
+/// 等容器后再将其初始化，会使容器处于错误的状态。
+impl<T: ?Sized + Hash> Hash for OnceInit<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get().ok().hash(state)
+    }
+}
 
 /// # [`StaticDefault`]
 ///
@@ -243,6 +1217,144 @@ pub unsafe trait StaticDefault {
     /// 返回类型的 `'static` 生命周期引用。
     fn static_default() -> &'static Self;
 }
+/// # [`ConstStaticDefault`]
+///
+/// [`StaticDefault`] 的安全版本：以关联常量给出默认值的 `'static` 引用，并自动实现 [`StaticDefault`].
+///
+/// 常量中的引用在编译期求值，由编译器保证来自常量提升或静态数据，不可能分配或泄漏内存，因此该特型无需 `unsafe`.
+/// 对于特型对象，可以直接以具体类型的值的引用作为默认值，会被自动转换：
+///
+/// ```rust
+/// use onceinit::{ConstStaticDefault, OnceInit};
+///
+/// pub trait Logger: Sync {
+///     fn log(&self, msg: &str);
+/// }
+/// struct NopLogger;
+/// impl Logger for NopLogger {
+///     fn log(&self, _: &str) {}
+/// }
+/// impl ConstStaticDefault for dyn Logger {
+///     const STATIC_DEFAULT: &'static Self = &NopLogger;
+/// }
+///
+/// static LOGGER: OnceInit<dyn Logger> = OnceInit::uninit();
+/// LOGGER.log("discarded");
+/// ```
+///
+/// 常量中的引用不能指向具有内部可变性（如原子类型、[`Mutex`](std::sync::Mutex)）的值，
+/// 且每次使用常量时得到的地址不保证相同。此类情形请使用静态变量并实现 [`StaticDefault`].
+///
+/// 该特型的所有实现者都经由 `unsafe impl<T: ?Sized + ConstStaticDefault> StaticDefault for T` 实现了 [`StaticDefault`],
+/// 因此同一类型只能实现两者之一：实现了该特型的类型无法再手动实现 [`StaticDefault`], 反之亦然。
+pub trait ConstStaticDefault: 'static {
+    /// 默认值的 `'static` 引用。
+    const STATIC_DEFAULT: &'static Self;
+}
+unsafe impl<T: ?Sized + ConstStaticDefault> StaticDefault for T {
+    #[inline]
+    fn static_default() -> &'static Self {
+        T::STATIC_DEFAULT
+    }
+}
+/// 以常量表达式为类型实现 [`StaticDefault`], 默认值存储在生成的静态变量中，不会泄漏内存。
+///
+/// 形如 `Type = value`, 可以用 `;` 分隔多项。`Type` 为特型对象时，`value` 可以是实现了该特型的具体类型的值，
+/// 会被自动转换。生成的静态变量位于函数内部，不会与其他名称冲突，该宏可以在模块或函数中使用。
+///
+/// 启用 `const-default` 特性后，还可以只列出类型（以 `,` 分隔），以 `const_default::ConstDefault::DEFAULT` 作为默认值。
+/// 静态变量不能是泛型的，且常量中的引用不能指向可能具有内部可变性的泛型值，因此无法为所有实现了
+/// `ConstDefault` 的类型提供通用实现，须为每个类型调用一次该宏。
+///
+/// ```rust
+/// use onceinit::{static_default, OnceInit};
+///
+/// pub struct Config {
+///     retries: u32,
+///     verbose: bool,
+/// }
+/// pub trait Logger: Sync {
+///     fn log(&self, msg: &str);
+/// }
+/// struct NopLogger;
+/// impl Logger for NopLogger {
+///     fn log(&self, _: &str) {}
+/// }
+///
+/// static_default! {
+///     Config = Config { retries: 3, verbose: false };
+///     dyn Logger = NopLogger;
+/// }
+///
+/// static CONFIG: OnceInit<Config> = OnceInit::uninit();
+/// static LOGGER: OnceInit<dyn Logger> = OnceInit::uninit();
+/// assert_eq!(CONFIG.retries, 3);
+/// LOGGER.log("discarded");
+/// ```
+#[macro_export]
+macro_rules! static_default {
+    ($($ty:ty = $value:expr);+ $(;)?) => {$(
+        unsafe impl $crate::StaticDefault for $ty {
+            #[inline]
+            fn static_default() -> &'static Self {
+                static DEFAULT: &'static $ty = &$value;
+                DEFAULT
+            }
+        }
+    )+};
+    ($($ty:ty),+ $(,)?) => {$(
+        $crate::static_default!($ty = <$ty as $crate::__private::ConstDefault>::DEFAULT);
+    )+};
+}
+/// 为特型生成不做任何事的实现，并以其作为该特型对象的 [`StaticDefault`], 常用于门面（facade）模式中未初始化时的默认实现。
+///
+/// 需要重新列出特型的方法：返回 `()` 的方法无需默认值；其他方法以 `= value` 给出返回值。
+/// 方法的参数会被忽略。与 [`static_default!`] 相同，特型对象须为 [`Sync`] 的（如特型以 [`Sync`] 为超特型）。
+///
+/// ```rust
+/// use onceinit::{nop_default, OnceInit};
+///
+/// pub trait Logger: Sync {
+///     fn log(&self, msg: &str);
+///     fn is_enabled(&self, level: u8) -> bool;
+///     fn name(&self) -> Option<&'static str>;
+/// }
+///
+/// nop_default! {
+///     /// 未初始化时使用的 `Logger`.
+///     dyn Logger => NopLogger {
+///         fn log(&self, msg: &str);
+///         fn is_enabled(&self, level: u8) -> bool = false;
+///         fn name(&self) -> Option<&'static str> = None;
+///     }
+/// }
+///
+/// static LOGGER: OnceInit<dyn Logger> = OnceInit::uninit();
+/// LOGGER.log("discarded");
+/// assert!(!LOGGER.is_enabled(0));
+/// assert_eq!(LOGGER.name(), None);
+/// ```
+#[macro_export]
+macro_rules! nop_default {
+    (
+        $(#[$attr:meta])*
+        $vis:vis dyn $trait:path => $nop:ident {
+            $(fn $method:ident(&self $(, $arg:ident: $arg_ty:ty)* $(,)?) $(-> $ret:ty = $default:expr)?;)*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $nop;
+        impl $trait for $nop {
+            $(
+                #[inline]
+                fn $method(&self $(, _: $arg_ty)*) $(-> $ret)? {
+                    $($default)?
+                }
+            )*
+        }
+        $crate::static_default!(dyn $trait = $nop);
+    };
+}
 impl<T: ?Sized + StaticDefault> Deref for OnceInit<T> {
     type Target = T;
 
@@ -251,6 +1363,16 @@ impl<T: ?Sized + StaticDefault> Deref for OnceInit<T> {
         self.get_or_default()
     }
 }
+/// 同 [`Deref`], 未初始化时返回 [`StaticDefault`] 提供的默认值。
+///
+/// 不实现 [`Borrow`](core::borrow::Borrow): [`OnceInit`] 的 [`Eq`] 与 [`Hash`] 将其视作 [`Option`],
+/// 与 `T` 的实现不一致，不满足 [`Borrow`](core::borrow::Borrow) 的要求。
+impl<T: ?Sized + StaticDefault> AsRef<T> for OnceInit<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self.get_or_default()
+    }
+}
 /// 指示拥有一个全局实例，但可能未初始化。
 pub trait UninitGlobalHolder<T: ?Sized> {
     /// 初始化内部数据。
@@ -269,6 +1391,7 @@ impl<T: ?Sized> UninitGlobalHolder<T> for OnceInit<T> {
     }
     /// 初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
     #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
     fn init_boxed(&self, data: Box<T>) -> Result<(), OnceInitError> {
         OnceInit::init_boxed(self, data)
     }
@@ -283,7 +1406,7 @@ pub trait UninitGlobal<T: ?Sized, M> {
     where
         M: UninitGlobalHolder<T> + 'static,
     {
-        Ok(Self::holder().init(data)?)
+        Self::holder().init(data)
     }
     #[inline]
     #[cfg(any(feature = "alloc", not(feature = "no_std")))]
@@ -291,6 +1414,6 @@ pub trait UninitGlobal<T: ?Sized, M> {
     where
         M: UninitGlobalHolder<T> + 'static,
     {
-        Ok(Self::holder().init_boxed(data)?)
+        Self::holder().init_boxed(data)
     }
 }
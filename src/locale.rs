@@ -0,0 +1,76 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInit, OnceInitError};
+use core::{cell::UnsafeCell, mem::MaybeUninit};
+
+/// 各错误对应的信息，作为一个整体发布，[`Display`](core::fmt::Display) 不会观察到新旧信息混杂的情形。
+struct Messages {
+    data_uninitialized: &'static str,
+    data_initialized: &'static str,
+    timed_out: &'static str,
+}
+
+/// [`MESSAGES`] 的存储，仅由完成初始化的线程写入一次。
+struct Slot(UnsafeCell<MaybeUninit<Messages>>);
+unsafe impl Sync for Slot {}
+
+static SLOT: Slot = Slot(UnsafeCell::new(MaybeUninit::uninit()));
+static MESSAGES: OnceInit<Messages> = OnceInit::uninit();
+
+/// 设置 [`OnceInitError`] 在 [`Display`](core::fmt::Display) 时输出的信息，只可调用一次。
+///
+/// 三个参数依次对应 [`DataUninitialized`](OnceInitError::DataUninitialized),
+/// [`DataInitialized`](OnceInitError::DataInitialized) 与 [`TimedOut`](OnceInitError::TimedOut).
+/// 调用前或未启用 `locale` 特性时，输出英文信息。
+///
+/// ``` rust
+/// onceinit::set_error_messages("数据未被初始化。", "数据已被初始化。", "等待初始化超时。").unwrap();
+/// assert_eq!(
+///     onceinit::OnceInitError::DataInitialized.to_string(),
+///     "数据已被初始化。"
+/// );
+/// ```
+pub fn set_error_messages(
+    uninit: &'static str,
+    init: &'static str,
+    timed_out: &'static str,
+) -> Result<(), OnceInitError> {
+    // 只有将状态由未初始化切换为正在初始化的线程会调用该闭包，且 `MESSAGES` 不会被重置，
+    // 因此 `SLOT` 至多被写入一次，写入前不存在对它的引用。
+    MESSAGES.init_internal(|| unsafe {
+        (*SLOT.0.get()).write(Messages {
+            data_uninitialized: uninit,
+            data_initialized: init,
+            timed_out,
+        })
+    })
+}
+
+pub(crate) fn localized_message(error: &OnceInitError) -> Option<&'static str> {
+    let messages = MESSAGES.get().ok()?;
+    Some(match error {
+        OnceInitError::DataUninitialized => messages.data_uninitialized,
+        OnceInitError::DataInitialized => messages.data_initialized,
+        OnceInitError::TimedOut => messages.timed_out,
+    })
+}
@@ -0,0 +1,55 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 与 [`once_cell::sync::OnceCell`] 之间的转换。
+//!
+//! 二者的区别在于，[`OnceCell<T>`] 拥有 `T`, 而 [`OnceInit<T>`] 只持有 `&'static T`:
+//!
+//! - 由 [`OnceCell<T>`] 转换时，数据会被移入 [`Box`] 并泄漏，之后不会被释放；
+//! - 转换为 [`OnceCell<T>`] 时，无法取得数据的所有权，因此需要 `T: Clone`.
+//!
+//! `once_cell::sync::Lazy` 可直接替换为 [`Lazy`](crate::Lazy), 其初始化函数需返回 `&'static T`.
+
+use crate::OnceInit;
+use once_cell::sync::OnceCell;
+
+/// 已设置时泄漏其中的数据并以之初始化，否则返回未初始化的 [`OnceInit`].
+impl<T> From<OnceCell<T>> for OnceInit<T> {
+    #[inline]
+    fn from(cell: OnceCell<T>) -> Self {
+        match cell.into_inner() {
+            Some(data) => OnceInit::new(Box::leak(Box::new(data))),
+            None => OnceInit::uninit(),
+        }
+    }
+}
+
+/// 已初始化时以内部数据的克隆设置 [`OnceCell`], 否则返回未设置的 [`OnceCell`].
+impl<T: Clone> From<OnceInit<T>> for OnceCell<T> {
+    #[inline]
+    fn from(cell: OnceInit<T>) -> Self {
+        match cell.get() {
+            Ok(data) => OnceCell::with_value(data.clone()),
+            Err(_) => OnceCell::new(),
+        }
+    }
+}
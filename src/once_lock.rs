@@ -0,0 +1,118 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 与 [`OnceLock`] 之间的转换，便于从 `OnceLock<&'static T>` 或 `OnceLock<T>` 迁移。
+
+use crate::{OnceInit, OnceInitError, UninitGlobalHolder};
+use std::sync::OnceLock;
+
+/// 若 `lock` 已设置，则返回以其中的引用初始化的 [`OnceInit`], 否则返回未初始化的 [`OnceInit`].
+impl<T: ?Sized> From<OnceLock<&'static T>> for OnceInit<T> {
+    #[inline]
+    fn from(lock: OnceLock<&'static T>) -> Self {
+        match lock.into_inner() {
+            Some(data) => OnceInit::new(data),
+            None => OnceInit::uninit(),
+        }
+    }
+}
+
+impl<T: ?Sized> OnceInit<T> {
+    /// 以当前数据构造 [`OnceLock`], 未初始化时返回未设置的 [`OnceLock`].
+    ///
+    /// 返回的是当前状态的快照，正在初始化时会等待其完成。
+    #[inline]
+    pub fn to_once_lock(&self) -> OnceLock<&'static T> {
+        let lock = OnceLock::new();
+        if let Ok(data) = self.get() {
+            let _ = lock.set(data);
+        }
+        lock
+    }
+}
+
+impl<T> OnceInit<T> {
+    /// 以 [`OnceLock`] 中的数据构造 [`OnceInit`], 未设置时返回未初始化的 [`OnceInit`].
+    ///
+    /// 已设置时会将数据移入 [`Box`] 并泄漏。
+    ///
+    /// 不提供对应的 `impl From<OnceLock<T>> for OnceInit<T>`: 它与 `From<OnceLock<&'static T>>`
+    /// 同时存在时，`OnceInit::from(lock)` 将无法推断类型。
+    #[inline]
+    pub fn from_once_lock(lock: OnceLock<T>) -> Self {
+        match lock.into_inner() {
+            Some(data) => OnceInit::new(Box::leak(Box::new(data))),
+            None => OnceInit::uninit(),
+        }
+    }
+    /// 以内部数据的克隆构造 [`OnceLock`], 未初始化时返回未设置的 [`OnceLock`].
+    ///
+    /// [`OnceInit`] 只持有 `'static` 引用，无法转移数据的所有权，因此需要 `T: Clone`.
+    /// 由于 [`to_once_lock`](Self::to_once_lock) 已用于返回 `OnceLock<&'static T>`, 该函数名为 `into_once_lock`.
+    #[inline]
+    pub fn into_once_lock(self) -> OnceLock<T>
+    where
+        T: Clone,
+    {
+        let lock = OnceLock::new();
+        if let Ok(data) = self.get() {
+            let _ = lock.set(data.clone());
+        }
+        lock
+    }
+    /// 已初始化时，返回以内部数据的克隆设置的 [`OnceLock`], 否则返回 [`None`].
+    #[inline]
+    pub fn as_once_lock(&self) -> Option<OnceLock<T>>
+    where
+        T: Clone,
+    {
+        self.get().ok().map(|data| OnceLock::from(data.clone()))
+    }
+}
+
+/// 同 [`OnceInit::into_once_lock`].
+impl<T: Clone> From<OnceInit<T>> for OnceLock<T> {
+    #[inline]
+    fn from(cell: OnceInit<T>) -> Self {
+        cell.into_once_lock()
+    }
+}
+
+impl<T: ?Sized> UninitGlobalHolder<T> for OnceLock<&'static T> {
+    /// 设置内部数据，已设置时返回 [`OnceInitError::DataInitialized`].
+    #[inline]
+    fn init(&self, data: &'static T) -> Result<(), OnceInitError> {
+        self.set(data).map_err(|_| OnceInitError::DataInitialized)
+    }
+    /// 设置内部数据，已设置时返回 [`OnceInitError::DataInitialized`].
+    ///
+    /// 只有成功设置时才会泄漏 `data`, 失败时 `data` 被丢弃。
+    #[inline]
+    fn init_boxed(&self, data: Box<T>) -> Result<(), OnceInitError> {
+        let mut data = Some(data);
+        self.get_or_init(|| Box::leak(data.take().unwrap()));
+        match data {
+            None => Ok(()),
+            Some(_) => Err(OnceInitError::DataInitialized),
+        }
+    }
+}
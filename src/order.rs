@@ -0,0 +1,109 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::OnceInit;
+use alloc::vec::Vec;
+
+/// # `InitOrder`
+/// 描述多个 [`OnceInit`] 之间的初始化顺序，并检查其是否被遵守。
+///
+/// 按 [`require_before`](Self::require_before) 的调用顺序登记，先登记的 [`OnceInit`] 应先被初始化。
+///
+/// ``` rust
+/// use onceinit::{InitOrder, OnceInit};
+///
+/// static A: OnceInit<str> = OnceInit::uninit();
+/// static B: OnceInit<str> = OnceInit::uninit();
+/// static C: OnceInit<str> = OnceInit::uninit();
+///
+/// let mut order = InitOrder::new();
+/// order.require_before(&A, "A");
+/// order.require_before(&B, "B");
+/// order.require_before(&C, "C");
+///
+/// A.init_str("a").unwrap();
+/// order.assert_init_order(&B);
+/// B.init_str("b").unwrap();
+/// assert_eq!(order.verify_all_initialized(), Err("C"));
+/// ```
+#[derive(Debug)]
+pub struct InitOrder<'a, T: ?Sized + 'static> {
+    cells: Vec<&'a OnceInit<T>>,
+    names: Vec<&'static str>,
+}
+
+impl<T: ?Sized> Default for InitOrder<'_, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T: ?Sized> InitOrder<'a, T> {
+    /// 返回空的 [`InitOrder`].
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            cells: Vec::new(),
+            names: Vec::new(),
+        }
+    }
+    /// 登记 `cell`, 它应在此前登记的 [`OnceInit`] 之后、此后登记的 [`OnceInit`] 之前初始化。
+    ///
+    /// `name` 用于错误信息。
+    #[inline]
+    pub fn require_before(&mut self, cell: &'a OnceInit<T>, name: &'static str) {
+        self.cells.push(cell);
+        self.names.push(name);
+    }
+    /// 按登记顺序检查，返回第一个未初始化的 [`OnceInit`] 的名称。正在初始化时会等待其完成。
+    pub fn verify_all_initialized(&self) -> Result<(), &'static str> {
+        self.check(self.cells.len())
+    }
+    /// 断言 `cell` 的所有前置 [`OnceInit`] 均已初始化，通常在初始化 `cell` 之前调用。
+    ///
+    /// `cell` 未登记时，视所有已登记的 [`OnceInit`] 为其前置。
+    ///
+    /// # Panics
+    ///
+    /// 存在未初始化的前置 [`OnceInit`] 时恐慌，信息中包含其名称。
+    #[track_caller]
+    pub fn assert_init_order(&self, cell: &OnceInit<T>) {
+        let position = self.cells.iter().position(|c| core::ptr::eq(*c, cell));
+        if let Err(missing) = self.check(position.unwrap_or(self.cells.len())) {
+            match position {
+                Some(i) => panic!(
+                    "`{missing}` must be initialized before `{}`.",
+                    self.names[i]
+                ),
+                None => panic!("`{missing}` must be initialized first."),
+            }
+        }
+    }
+    /// 检查前 `len` 个 [`OnceInit`].
+    fn check(&self, len: usize) -> Result<(), &'static str> {
+        match self.cells[..len].iter().position(|c| c.get().is_err()) {
+            Some(i) => Err(self.names[i]),
+            None => Ok(()),
+        }
+    }
+}
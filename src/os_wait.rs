@@ -0,0 +1,55 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 借助操作系统等待初始化结束，见 `atomic-wait` 特性。
+//!
+//! `atomic-wait` 只支持 [`AtomicU32`], 而 [`OnceInit`](crate::OnceInit) 的 `state` 为 `usize`,
+//! 为了不改变布局，所有 [`OnceInit`](crate::OnceInit) 共用一个计数器：每次结束初始化时递增并唤醒所有等待者，
+//! 等待者醒来后重新检查各自的状态。初始化很少发生，因此共用计数器带来的额外唤醒可以忽略。
+
+use core::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+/// 每次结束初始化时递增。
+static EPOCH: AtomicU32 = AtomicU32::new(0);
+/// 正在等待的线程数，为 `0` 时唤醒无需系统调用。
+static SLEEPERS: AtomicUsize = AtomicUsize::new(0);
+
+/// 在 `is_initializing` 返回 `true` 时阻塞，直到某次初始化结束（或虚假唤醒）。
+///
+/// 调用者需在返回后重新检查状态。
+pub(crate) fn wait(is_initializing: impl FnOnce() -> bool) {
+    SLEEPERS.fetch_add(1, Ordering::SeqCst);
+    let epoch = EPOCH.load(Ordering::SeqCst);
+    // 读取计数器后再检查状态，以免错过检查后、等待前发生的唤醒。
+    if is_initializing() {
+        atomic_wait::wait(&EPOCH, epoch);
+    }
+    SLEEPERS.fetch_sub(1, Ordering::SeqCst);
+}
+
+/// 唤醒所有等待初始化结束的线程，需在状态更新后调用。
+pub(crate) fn wake_all() {
+    EPOCH.fetch_add(1, Ordering::SeqCst);
+    if SLEEPERS.load(Ordering::SeqCst) != 0 {
+        atomic_wait::wake_all(&EPOCH);
+    }
+}
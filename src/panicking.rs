@@ -0,0 +1,158 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInit, OnceInitError, OnceInitReader, OnceInitState};
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use alloc::boxed::Box;
+use core::fmt::Debug;
+
+/// # `PanickingOnceInit`
+/// 在类型层面决定出错时是否恐慌的 [`OnceInit`].
+///
+/// - `PANIC_ON_UNINIT` 为 `true` 时，[`get`](Self::get) 直接返回 `&'static T`, 未初始化时恐慌；
+/// - `PANIC_ON_DOUBLE_INIT` 为 `true` 时，[`init`](Self::init) 与 [`init_boxed`](Self::init_boxed)
+///   不返回 [`Result`], 重复初始化时恐慌。
+///
+/// 对应的标志为 `false` 时，上述函数与 [`OnceInit`] 中的同名函数相同。常用的组合见 [`PanicOnUninit`] 与 [`PanicOnDoubleInit`].
+///
+/// 该类型不解引用为 [`OnceInit`], 以免经由其他初始化函数绕过 `PANIC_ON_DOUBLE_INIT`;
+/// 只读的 api 见 [`is_initialized`](Self::is_initialized), [`state`](Self::state) 与 [`reader`](Self::reader).
+///
+/// ``` rust
+/// use onceinit::PanicOnUninit;
+///
+/// static PORT: PanicOnUninit<u16> = PanicOnUninit::uninit();
+/// PORT.init(&8080).unwrap();
+/// assert_eq!(*PORT.get(), 8080);
+/// ```
+#[repr(transparent)]
+pub struct PanickingOnceInit<
+    T: ?Sized + 'static,
+    const PANIC_ON_UNINIT: bool,
+    const PANIC_ON_DOUBLE_INIT: bool,
+> {
+    cell: OnceInit<T>,
+}
+
+/// 未初始化时 [`get`](PanickingOnceInit::get) 恐慌的 [`PanickingOnceInit`].
+pub type PanicOnUninit<T> = PanickingOnceInit<T, true, false>;
+/// 重复初始化时 [`init`](PanickingOnceInit::init) 恐慌的 [`PanickingOnceInit`].
+pub type PanicOnDoubleInit<T> = PanickingOnceInit<T, false, true>;
+
+impl<T: ?Sized, const U: bool, const D: bool> PanickingOnceInit<T, U, D> {
+    /// 返回未初始化的实例。
+    #[inline]
+    pub const fn uninit() -> Self {
+        Self {
+            cell: OnceInit::uninit(),
+        }
+    }
+    /// 返回以 `data` 初始化的实例。
+    #[inline]
+    pub const fn new(data: &'static T) -> Self {
+        Self {
+            cell: OnceInit::new(data),
+        }
+    }
+    /// 返回内部 [`OnceInit`] 的只读视图，见 [`OnceInitReader`].
+    #[inline]
+    pub const fn reader(&self) -> OnceInitReader<'_, T> {
+        self.cell.reader()
+    }
+    /// 同 [`OnceInit::is_initialized`].
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.cell.is_initialized()
+    }
+    /// 同 [`OnceInit::state`].
+    #[inline]
+    pub fn state(&self) -> OnceInitState {
+        self.cell.state()
+    }
+}
+impl<T: ?Sized, const D: bool> PanickingOnceInit<T, false, D> {
+    /// 同 [`OnceInit::get`].
+    #[inline]
+    pub fn get(&self) -> Result<&'static T, OnceInitError> {
+        self.cell.get()
+    }
+}
+impl<T: ?Sized, const D: bool> PanickingOnceInit<T, true, D> {
+    /// 返回内部数据。正在初始化时会等待其完成。
+    ///
+    /// # Panics
+    ///
+    /// 数据未初始化时恐慌。
+    #[inline]
+    #[track_caller]
+    pub fn get(&self) -> &'static T {
+        match self.cell.get() {
+            Ok(data) => data,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+impl<T: ?Sized, const U: bool> PanickingOnceInit<T, U, false> {
+    /// 同 [`OnceInit::init`].
+    #[inline]
+    pub fn init(&self, data: &'static T) -> Result<(), OnceInitError> {
+        self.cell.init(data)
+    }
+    /// 同 [`OnceInit::init_boxed`].
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_boxed(&self, data: Box<T>) -> Result<(), OnceInitError> {
+        self.cell.init_boxed(data)
+    }
+}
+impl<T: ?Sized, const U: bool> PanickingOnceInit<T, U, true> {
+    /// 初始化内部数据。
+    ///
+    /// # Panics
+    ///
+    /// 数据已被初始化或正在初始化时恐慌。
+    #[inline]
+    #[track_caller]
+    pub fn init(&self, data: &'static T) {
+        if let Err(e) = self.cell.init(data) {
+            panic!("{e}")
+        }
+    }
+    /// 以 [`Box`] 初始化内部数据。
+    ///
+    /// # Panics
+    ///
+    /// 数据已被初始化或正在初始化时恐慌，此时 `data` 被丢弃。
+    #[inline]
+    #[track_caller]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_boxed(&self, data: Box<T>) {
+        if let Err(e) = self.cell.init_boxed(data) {
+            panic!("{e}")
+        }
+    }
+}
+impl<T: ?Sized + Debug, const U: bool, const D: bool> Debug for PanickingOnceInit<T, U, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.cell.fmt(f)
+    }
+}
@@ -0,0 +1,74 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInit, OnceInitError, StaticDefault};
+use std::{
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+impl OnceInit<Path> {
+    /// 以 `'static` 路径初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    pub fn init_path(&self, p: &'static Path) -> Result<(), OnceInitError> {
+        self.init(p)
+    }
+    /// 以 [`PathBuf`] 初始化内部数据，只可调用一次。
+    ///
+    /// 成功时会泄漏 `p` 的缓冲区；失败时原样返回 `p`.
+    #[inline]
+    pub fn init_pathbuf(&self, p: PathBuf) -> Result<(), PathBuf> {
+        self.try_init_boxed_take(p.into_boxed_path())
+            .map(|_| ())
+            .map_err(Path::into_path_buf)
+    }
+}
+impl OnceInit<OsStr> {
+    /// 以 `'static` 系统字符串初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    pub fn init_os_str(&self, s: &'static OsStr) -> Result<(), OnceInitError> {
+        self.init(s)
+    }
+    /// 以 [`OsString`] 初始化内部数据，只可调用一次。
+    ///
+    /// 成功时会泄漏 `s` 的缓冲区；失败时原样返回 `s`.
+    #[inline]
+    pub fn init_os_string(&self, s: OsString) -> Result<(), OsString> {
+        self.try_init_boxed_take(s.into_boxed_os_str())
+            .map(|_| ())
+            .map_err(OsStr::into_os_string)
+    }
+}
+/// 返回空路径。
+unsafe impl StaticDefault for Path {
+    #[inline]
+    fn static_default() -> &'static Self {
+        Path::new("")
+    }
+}
+/// 返回空字符串。
+unsafe impl StaticDefault for OsStr {
+    #[inline]
+    fn static_default() -> &'static Self {
+        OsStr::new("")
+    }
+}
@@ -0,0 +1,49 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 为基本类型实现 [`StaticDefault`].
+
+use crate::StaticDefault;
+
+// 这些实现没有迁移到 `ConstStaticDefault`: 常量每次使用时得到的地址不保证相同，
+// 而这里的默认值存储在静态变量中，每次调用 `static_default` 都返回同一地址。
+macro_rules! impl_static_default {
+    ($($ty:ty => $default:expr),* $(,)?) => {$(
+        /// 返回与 [`Default::default`] 相同的值，存储在静态变量中，不会泄漏内存。
+        unsafe impl StaticDefault for $ty {
+            #[inline]
+            fn static_default() -> &'static Self {
+                static DEFAULT: $ty = $default;
+                &DEFAULT
+            }
+        }
+    )*};
+}
+
+impl_static_default! {
+    () => (),
+    bool => false,
+    char => '\0',
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    f32 => 0.0, f64 => 0.0,
+}
@@ -0,0 +1,92 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInit, OnceInitError, OnceInitState};
+use core::fmt::Debug;
+
+/// # `OnceInitReader`
+/// [`OnceInit`] 的只读视图，只提供读取操作，无法初始化。
+///
+/// 可以将其交给不应设置全局变量的组件，在类型层面限制其权限。
+///
+/// ``` rust
+/// use onceinit::{OnceInit, OnceInitReader};
+///
+/// static CONFIG: OnceInit<str> = OnceInit::uninit();
+///
+/// fn report(config: OnceInitReader<'_, str>) -> &'static str {
+///     config.get().unwrap_or("<unset>")
+/// }
+///
+/// assert_eq!(report(CONFIG.reader()), "<unset>");
+/// CONFIG.init("ready").unwrap();
+/// assert_eq!(report(CONFIG.reader()), "ready");
+/// ```
+pub struct OnceInitReader<'a, T: ?Sized + 'static>(&'a OnceInit<T>);
+
+impl<'a, T: ?Sized> OnceInitReader<'a, T> {
+    /// 返回 `cell` 的只读视图。
+    #[inline]
+    pub const fn new(cell: &'a OnceInit<T>) -> Self {
+        Self(cell)
+    }
+    /// 同 [`OnceInit::get`].
+    #[inline]
+    pub fn get(&self) -> Result<&'static T, OnceInitError> {
+        self.0.get()
+    }
+    /// 同 [`OnceInit::state`].
+    #[inline]
+    pub fn state(&self) -> OnceInitState {
+        self.0.state()
+    }
+    /// 同 [`OnceInit::is_initialized`].
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.0.is_initialized()
+    }
+}
+impl<T: ?Sized> OnceInit<T> {
+    /// 返回只读视图，见 [`OnceInitReader`].
+    #[inline]
+    pub const fn reader(&self) -> OnceInitReader<'_, T> {
+        OnceInitReader::new(self)
+    }
+}
+impl<T: ?Sized> Clone for OnceInitReader<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized> Copy for OnceInitReader<'_, T> {}
+impl<'a, T: ?Sized> From<&'a OnceInit<T>> for OnceInitReader<'a, T> {
+    #[inline]
+    fn from(cell: &'a OnceInit<T>) -> Self {
+        Self::new(cell)
+    }
+}
+impl<T: ?Sized + Debug> Debug for OnceInitReader<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("OnceInitReader").field(self.0).finish()
+    }
+}
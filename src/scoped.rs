@@ -0,0 +1,131 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInitError, OnceInitState, INITIALIZED, INITIALIZING, UNINITIALIZED};
+use core::{
+    cell::UnsafeCell,
+    fmt::Debug,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// # `OnceInitRef`
+/// 与 [`OnceInit`](crate::OnceInit) 类似，但存储的是生命周期为 `'a` 的引用，适用于作用域内的数据。
+///
+/// 借用检查器保证 [`OnceInitRef`] 不会比其中的数据活得更久，因此当 `T: Sync` 时它同样是 [`Sync`] 的，
+/// 可以在 [`std::thread::scope`] 等场合中共享。
+///
+/// 由于初始化只是写入一个引用，不会调用用户代码，该类型只提供 [`init`](Self::init), [`get`](Self::get)
+/// 与 [`state`](Self::state) 等基本操作。
+///
+/// ``` rust
+/// use onceinit::OnceInitRef;
+///
+/// let config = String::from("scoped");
+/// let cell = OnceInitRef::uninit();
+/// std::thread::scope(|s| {
+///     s.spawn(|| cell.init(config.as_str()).unwrap());
+/// });
+/// assert_eq!(cell.get().unwrap(), "scoped");
+/// ```
+pub struct OnceInitRef<'a, T: ?Sized> {
+    pub(crate) state: AtomicUsize,
+    data: UnsafeCell<Option<&'a T>>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for OnceInitRef<'_, T> {}
+
+impl<'a, T: ?Sized> OnceInitRef<'a, T> {
+    /// 返回未初始化的实例。
+    #[inline]
+    pub const fn uninit() -> Self {
+        Self {
+            state: AtomicUsize::new(UNINITIALIZED),
+            data: UnsafeCell::new(None),
+        }
+    }
+    /// 返回以 `data` 初始化的实例。
+    #[inline]
+    pub const fn new(data: &'a T) -> Self {
+        Self {
+            state: AtomicUsize::new(INITIALIZED),
+            data: UnsafeCell::new(Some(data)),
+        }
+    }
+    /// 返回内部数据，若未初始化，则返回 [`OnceInitError`]. 正在初始化时会等待其完成。
+    #[inline]
+    pub fn get(&self) -> Result<&'a T, OnceInitError> {
+        loop {
+            match self.state.load(Ordering::Acquire) {
+                INITIALIZED => return Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
+                // 只需等待一次指针写入。
+                INITIALIZING => core::hint::spin_loop(),
+                _ => return Err(OnceInitError::DataUninitialized),
+            }
+        }
+    }
+    /// 只读取一次状态，不等待。未初始化或正在初始化时返回对应的状态，用于格式化输出等不应阻塞的场合。
+    #[inline]
+    fn peek(&self) -> Result<&'a T, OnceInitState> {
+        match self.state.load(Ordering::Acquire) {
+            INITIALIZED => Ok(unsafe { (*self.data.get()).unwrap_unchecked() }),
+            INITIALIZING => Err(OnceInitState::INITIALIZING),
+            _ => Err(OnceInitState::UNINITIALIZED),
+        }
+    }
+    /// 初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    pub fn init(&self, data: &'a T) -> Result<(), OnceInitError> {
+        self.state
+            .compare_exchange(
+                UNINITIALIZED,
+                INITIALIZING,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .map_err(|_| OnceInitError::DataInitialized)?;
+        unsafe { *self.data.get() = Some(data) }
+        self.state.store(INITIALIZED, Ordering::Release);
+        Ok(())
+    }
+    /// 返回数据状态，见 [`OnceInitState`]. 正在初始化时会等待其完成。
+    #[inline]
+    pub fn state(&self) -> OnceInitState {
+        match self.get() {
+            Ok(_) => OnceInitState::INITIALIZED,
+            Err(_) => OnceInitState::UNINITIALIZED,
+        }
+    }
+}
+
+/// 已初始化时输出 `OnceInitRef(data)`, 否则输出 `OnceInitRef(<uninit>)` 或 `OnceInitRef(<initializing>)`.
+///
+/// 同 [`OnceInit`](crate::OnceInit) 的 [`Debug`] 实现，只读取一次状态，不会等待正在进行的初始化。
+impl<T: ?Sized + Debug> Debug for OnceInitRef<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut d = f.debug_tuple("OnceInitRef");
+        match self.peek() {
+            Ok(data) => d.field(&data),
+            Err(state) => d.field(&format_args!("{}", state.placeholder())),
+        };
+        d.finish()
+    }
+}
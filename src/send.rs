@@ -0,0 +1,113 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! [`SendOnceInit`]: 可在线程间移动、但不可共享的 [`OnceInit`](crate::OnceInit).
+
+use crate::OnceInitError;
+use alloc::boxed::Box;
+use core::{cell::OnceCell, fmt::Debug};
+
+/// # `SendOnceInit`
+/// 仅可设置一次数据的类型，拥有其中的数据，是 [`Send`] 而非 [`Sync`] 的。
+///
+/// 与 [`OnceInit`](crate::OnceInit) 的区别：
+///
+/// - [`OnceInit<T>`](crate::OnceInit) 只持有 `&'static T`, 要求 `T: Sync`, 之后既是 [`Send`] 也是 [`Sync`] 的，适用于全局变量；
+/// - [`SendOnceInit<T>`] 拥有其中的数据，只要求 `T: Send`, 是 [`Send`] 而非 [`Sync`] 的，
+///   适用于随线程移动的、每个线程一份的“全局”状态（如移动到工作线程中的上下文）。
+///   数据会在 [`SendOnceInit`] 被丢弃时释放，而不是被泄漏。
+///
+/// ``` rust
+/// use onceinit::SendOnceInit;
+/// use std::cell::Cell;
+///
+/// // `Cell` 不是 `Sync` 的，无法存入 `OnceInit`, 但可以存入 `SendOnceInit` 并移动到其他线程。
+/// let cell: SendOnceInit<Cell<u32>> = SendOnceInit::uninit();
+/// cell.init_boxed(Box::new(Cell::new(1))).unwrap();
+/// std::thread::spawn(move || {
+///     cell.get().unwrap().set(2);
+///     assert_eq!(cell.get().unwrap().get(), 2);
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+pub struct SendOnceInit<T: ?Sized> {
+    cell: OnceCell<Box<T>>,
+}
+
+impl<T: ?Sized> SendOnceInit<T> {
+    /// 返回未初始化的实例。
+    #[inline]
+    pub const fn uninit() -> Self {
+        Self {
+            cell: OnceCell::new(),
+        }
+    }
+    /// 返回内部数据，若未初始化，则返回 [`OnceInitError::DataUninitialized`].
+    #[inline]
+    pub fn get(&self) -> Result<&T, OnceInitError> {
+        self.cell
+            .get()
+            .map(|data| &**data)
+            .ok_or(OnceInitError::DataUninitialized)
+    }
+    /// 初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    pub fn init_boxed(&self, data: Box<T>) -> Result<(), OnceInitError> {
+        self.cell
+            .set(data)
+            .map_err(|_| OnceInitError::DataInitialized)
+    }
+    /// 数据是否已被初始化。
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.cell.get().is_some()
+    }
+    /// 取出内部数据，并将其重置为未初始化，之后可以重新初始化。未初始化时返回 [`None`].
+    ///
+    /// 由于需要 `&mut self`, 不会与其他读写操作竞争，因此是安全的。
+    #[inline]
+    pub fn take(&mut self) -> Option<Box<T>> {
+        self.cell.take()
+    }
+    /// 取出内部数据，未初始化时返回 [`None`].
+    #[inline]
+    pub fn into_inner(self) -> Option<Box<T>> {
+        self.cell.into_inner()
+    }
+}
+impl<T: ?Sized> Default for SendOnceInit<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::uninit()
+    }
+}
+impl<T: ?Sized + Debug> Debug for SendOnceInit<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut d = f.debug_tuple("SendOnceInit");
+        match self.get() {
+            Ok(data) => d.field(&data),
+            Err(_) => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
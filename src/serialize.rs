@@ -0,0 +1,83 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! `serde` 支持。
+
+use crate::{OnceInit, OnceInitError, OnceInitState};
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use alloc::boxed::Box;
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use serde::{Deserialize, Deserializer};
+use serde::{Serialize, Serializer};
+
+/// 已初始化时序列化为 `Some(data)`, 否则序列化为 `None`, 如在 JSON 中为 `null`.
+///
+/// 正在初始化时会等待其完成。
+impl<T: ?Sized + Serialize> Serialize for OnceInit<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.get() {
+            Ok(data) => serializer.serialize_some(data),
+            Err(_) => serializer.serialize_none(),
+        }
+    }
+}
+
+/// 与 [`Serialize`] 对应：`None`（如 JSON 中的 `null`）反序列化为未初始化的 [`OnceInit`],
+/// 否则将数据移入 [`Box`] 并泄漏，返回已初始化的 [`OnceInit`].
+///
+/// **每次反序列化都会泄漏一次数据**，不宜用于反复加载的数据。
+///
+/// 结构体字段缺失时，`serde` 不会将其视作 `None`, 如需此时得到未初始化的 [`OnceInit`], 可为字段标注
+/// `#[serde(default = "OnceInit::uninit")]`.
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OnceInit<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(data) => OnceInit::new(Box::leak(Box::new(data))),
+            None => OnceInit::uninit(),
+        })
+    }
+}
+
+/// 序列化为单元变体，变体名与 Rust 中的相同。
+impl Serialize for OnceInitState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = match self {
+            OnceInitState::UNINITIALIZED => "UNINITIALIZED",
+            OnceInitState::INITIALIZING => "INITIALIZING",
+            OnceInitState::INITIALIZED => "INITIALIZED",
+        };
+        serializer.serialize_unit_variant("OnceInitState", *self as u32, name)
+    }
+}
+
+/// 序列化为单元变体，变体名与 Rust 中的相同。
+impl Serialize for OnceInitError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (index, name) = match self {
+            OnceInitError::DataUninitialized => (0, "DataUninitialized"),
+            OnceInitError::DataInitialized => (1, "DataInitialized"),
+            OnceInitError::TimedOut => (2, "TimedOut"),
+        };
+        serializer.serialize_unit_variant("OnceInitError", index, name)
+    }
+}
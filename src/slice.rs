@@ -0,0 +1,111 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{ConstStaticDefault, OnceInit, OnceInitError};
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use alloc::{borrow::Cow, vec::Vec};
+use core::{ops::Index, slice::SliceIndex};
+
+impl<T> OnceInit<[T]> {
+    /// 返回内部的切片，若未初始化，则返回 [`OnceInitError`].
+    ///
+    /// 与 [`iter`](Self::iter) 不同，该函数会区分“未初始化”与“空切片”。
+    #[inline]
+    pub fn as_slice(&self) -> Result<&'static [T], OnceInitError> {
+        self.get()
+    }
+    /// 返回内部切片的迭代器。
+    ///
+    /// 若未初始化，则返回空的迭代器而非错误。如需区分“未初始化”与“空切片”，请使用 [`as_slice`](Self::as_slice).
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'static, T> {
+        self.as_slice().unwrap_or(&[]).iter()
+    }
+    /// 以 `'static` 切片初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    pub fn init_slice(&self, s: &'static [T]) -> Result<(), OnceInitError> {
+        self.init(s)
+    }
+    /// 以 [`Vec`] 初始化内部数据，只可调用一次。
+    ///
+    /// 成功时 `v` 会被转换为 [`Box<[T]>`](alloc::boxed::Box) 并泄漏；失败时原样返回 `v`.
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_vec(&self, v: Vec<T>) -> Result<(), Vec<T>> {
+        self.try_init_boxed_take(v.into_boxed_slice())
+            .map(|_| ())
+            .map_err(Vec::from)
+    }
+    /// 返回内部切片的长度，若未初始化，则返回 [`None`].
+    #[inline]
+    pub fn len(&self) -> Option<usize> {
+        self.as_slice().ok().map(<[T]>::len)
+    }
+    /// 返回内部切片是否为空，若未初始化，则返回 [`None`].
+    #[inline]
+    pub fn is_empty(&self) -> Option<bool> {
+        self.as_slice().ok().map(<[T]>::is_empty)
+    }
+    /// 以 [`Cow`] 初始化内部数据，只可调用一次。
+    ///
+    /// [`Cow::Borrowed`] 不会引起任何分配；[`Cow::Owned`] 会被转换为 [`Box<[T]>`](alloc::boxed::Box) 并泄漏。
+    /// 失败时原样返回 `s`.
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_cow(&self, s: Cow<'static, [T]>) -> Result<(), Cow<'static, [T]>>
+    where
+        T: Clone,
+    {
+        match s {
+            Cow::Borrowed(s) => self.init(s).map_err(|_| Cow::Borrowed(s)),
+            Cow::Owned(v) => self.init_vec(v).map_err(Cow::Owned),
+        }
+    }
+}
+/// 返回空切片，对 `[u8]` 与 `[c_char]` 等 ffi 常用的切片同样适用。
+///
+/// 空切片的指针非空且对齐，可以安全地交给 [`slice::from_raw_parts`](core::slice::from_raw_parts) 等函数。
+impl<T: 'static> ConstStaticDefault for [T] {
+    const STATIC_DEFAULT: &'static Self = &[];
+}
+/// 经由 [`get_or_default`](OnceInit::get_or_default) 索引，未初始化时内部数据为空切片，任何索引都会越界恐慌。
+impl<T: 'static, I: SliceIndex<[T]>> Index<I> for OnceInit<[T]> {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.get_or_default()[index]
+    }
+}
+/// 同 [`OnceInit::iter`], 未初始化时不产生任何元素。
+///
+/// 因此无法再为所有的 `&OnceInit<T>` 实现产生 0 或 1 个 `&'static T` 的 [`IntoIterator`]: 两者会重叠，
+/// 且对切片而言，逐个产生元素更符合直觉。需要这种迭代器时，请使用 [`OnceInit::iter_opt`].
+impl<T: 'static> IntoIterator for &OnceInit<[T]> {
+    type Item = &'static T;
+    type IntoIter = core::slice::Iter<'static, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
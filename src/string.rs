@@ -0,0 +1,81 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{ConstStaticDefault, OnceInit, OnceInitError};
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+use alloc::{borrow::Cow, string::String};
+
+impl OnceInit<str> {
+    /// 以 `'static` 字符串初始化内部数据，只可调用一次，成功则初始化完成，之后调用均会返回错误。
+    #[inline]
+    pub fn init_str(&self, s: &'static str) -> Result<(), OnceInitError> {
+        self.init(s)
+    }
+    /// 以 [`String`] 初始化内部数据，只可调用一次。
+    ///
+    /// 成功时会泄漏 `s` 的缓冲区；失败时原样返回 `s`.
+    ///
+    /// ``` rust
+    /// use onceinit::OnceInit;
+    ///
+    /// static HOSTNAME: OnceInit<str> = OnceInit::uninit();
+    ///
+    /// let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| "localhost".to_owned());
+    /// HOSTNAME.init_string(hostname).unwrap();
+    /// assert!(HOSTNAME.as_str().ok().is_some());
+    /// ```
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_string(&self, s: String) -> Result<(), String> {
+        self.try_init_boxed_take(s.into_boxed_str())
+            .map(|_| ())
+            .map_err(String::from)
+    }
+    /// 以 [`Cow`] 初始化内部数据，只可调用一次。
+    ///
+    /// [`Cow::Borrowed`] 不会引起任何分配；[`Cow::Owned`] 同 [`init_string`](Self::init_string), 成功时会泄漏其缓冲区。
+    /// 失败时原样返回 `s`.
+    #[inline]
+    #[cfg(any(feature = "alloc", not(feature = "no_std")))]
+    pub fn init_cow(&self, s: Cow<'static, str>) -> Result<(), Cow<'static, str>> {
+        match s {
+            Cow::Borrowed(s) => self.init_str(s).map_err(|_| Cow::Borrowed(s)),
+            Cow::Owned(s) => self.init_string(s).map_err(Cow::Owned),
+        }
+    }
+    /// 返回内部的字符串，若未初始化，则返回 [`OnceInitError`].
+    #[inline]
+    pub fn as_str(&self) -> Result<&'static str, OnceInitError> {
+        self.get()
+    }
+    /// 返回内部字符串的字节长度，若未初始化，则返回 [`None`].
+    ///
+    /// 与经由 [`Deref`](core::ops::Deref) 调用的 [`str::len`] 不同，未初始化时不会返回空字符串的长度 `0`.
+    #[inline]
+    pub fn byte_len(&self) -> Option<usize> {
+        self.as_str().ok().map(str::len)
+    }
+}
+/// 返回空字符串。
+impl ConstStaticDefault for str {
+    const STATIC_DEFAULT: &'static Self = "";
+}
@@ -0,0 +1,135 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! **仅供测试使用**的工具与断言宏，需启用 `test-util` 特性。
+
+use crate::{OnceInit, OnceInitState};
+
+impl<T: ?Sized> OnceInit<T> {
+    /// **仅供测试使用。** 直接设置内部状态，见 [`force_raw_state`](Self::force_raw_state).
+    ///
+    /// # Safety
+    ///
+    /// 同 [`force_raw_state`](Self::force_raw_state).
+    #[inline]
+    pub unsafe fn force_state(&self, s: OnceInitState) {
+        unsafe { self.force_raw_state(s as usize) }
+    }
+    /// **仅供测试使用。** 直接设置内部状态值，可用于确定性地测试正在初始化等中间状态。
+    ///
+    /// 设置后会唤醒等待初始化结束的任务。
+    ///
+    /// # Safety
+    ///
+    /// 该函数会破坏 [`OnceInit`] 的不变量，调用者须保证：
+    ///
+    /// - `state` 为 [`UNINITIALIZED`](crate::UNINITIALIZED), [`INITIALIZING`](crate::INITIALIZING) 或 [`INITIALIZED`](crate::INITIALIZED) 之一；
+    /// - 仅当内部数据已被设置过时，才可设置为 [`INITIALIZED`](crate::INITIALIZED);
+    /// - 设置为 [`INITIALIZING`](crate::INITIALIZING) 后，最终须再次设置为其他状态，否则等待者将永远等待；
+    /// - 没有其他线程正在初始化该 [`OnceInit`].
+    #[inline]
+    pub unsafe fn force_raw_state(&self, state: usize) {
+        self.publish(state)
+    }
+}
+
+/// 断言 [`OnceInit`] 已初始化，失败时的 panic 信息包含调用处的文件与行号。
+///
+/// 不会等待正在进行的初始化。
+///
+/// ```
+/// # use onceinit::{OnceInit, assert_initialized};
+/// static DATA: u32 = 1;
+/// let cell = OnceInit::new(&DATA);
+/// assert_initialized!(cell);
+/// ```
+#[macro_export]
+macro_rules! assert_initialized {
+    ($cell:expr $(,)?) => {
+        match &$cell {
+            cell => ::core::assert!(
+                $crate::OnceInit::is_initialized(cell),
+                "assertion failed at {}:{}: `{}` is not initialized (state: {:?})",
+                ::core::file!(),
+                ::core::line!(),
+                ::core::stringify!($cell),
+                $crate::OnceInit::state(cell),
+            ),
+        }
+    };
+}
+
+/// 断言 [`OnceInit`] 未初始化，失败时的 panic 信息包含调用处的文件与行号。
+///
+/// ```
+/// # use onceinit::{OnceInit, assert_uninitialized};
+/// let cell: OnceInit<u32> = OnceInit::uninit();
+/// assert_uninitialized!(cell);
+/// ```
+#[macro_export]
+macro_rules! assert_uninitialized {
+    ($cell:expr $(,)?) => {
+        match $crate::OnceInit::state(&$cell) {
+            state => ::core::assert!(
+                state == $crate::OnceInitState::UNINITIALIZED,
+                "assertion failed at {}:{}: `{}` is not uninitialized (state: {:?})",
+                ::core::file!(),
+                ::core::line!(),
+                ::core::stringify!($cell),
+                state,
+            ),
+        }
+    };
+}
+
+/// 断言 [`OnceInit`] 已初始化且其值等于 `expected`, 失败时的 panic 信息包含调用处的文件与行号。
+///
+/// 比较的是 `*data == expected`, 因此 `expected` 为值而非引用。
+///
+/// ```
+/// # use onceinit::{OnceInit, assert_initialized_eq};
+/// static DATA: u32 = 1;
+/// let cell = OnceInit::new(&DATA);
+/// assert_initialized_eq!(cell, 1);
+/// ```
+#[macro_export]
+macro_rules! assert_initialized_eq {
+    ($cell:expr, $expected:expr $(,)?) => {
+        match $crate::OnceInit::get(&$cell) {
+            ::core::result::Result::Ok(data) => ::core::assert_eq!(
+                *data,
+                $expected,
+                "assertion failed at {}:{}: `{}` has an unexpected value",
+                ::core::file!(),
+                ::core::line!(),
+                ::core::stringify!($cell),
+            ),
+            ::core::result::Result::Err(e) => ::core::panic!(
+                "assertion failed at {}:{}: `{}` is not initialized: {}",
+                ::core::file!(),
+                ::core::line!(),
+                ::core::stringify!($cell),
+                e,
+            ),
+        }
+    };
+}
@@ -20,6 +20,8 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use crate::{OnceInit, OnceInitError};
+
 // log 门面库的类似实现。
 mod log {
     use crate::{OnceInit, StaticDefault};
@@ -72,3 +74,1888 @@ fn test_logger() {
     a_logger::ALogger::init().unwrap();
     hello_world::hello_world();
 }
+#[test]
+fn test_init_raw() {
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let ptr: *const u32 = Box::into_raw(Box::new(42));
+    unsafe { cell.init_raw(ptr) }.unwrap();
+    assert_eq!(*cell.get().unwrap(), 42);
+    assert!(core::ptr::eq(cell.get().unwrap(), ptr));
+    assert!(matches!(
+        unsafe { cell.init_raw(ptr) },
+        Err(OnceInitError::DataInitialized)
+    ));
+
+    let cell: OnceInit<dyn log::Logger> = OnceInit::uninit();
+    let ptr: *const dyn log::Logger = Box::into_raw(Box::new(a_logger::ALogger));
+    unsafe { cell.init_raw(ptr) }.unwrap();
+    cell.get().unwrap().log("init from raw pointer.");
+}
+#[test]
+fn test_init_overwrite() {
+    static OLD: u32 = 1;
+    static NEW: u32 = 2;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    unsafe { cell.init_overwrite(&OLD) };
+    let old = cell.get().unwrap();
+    assert_eq!(*old, 1);
+    unsafe { cell.init_overwrite(&NEW) };
+    assert_eq!(*cell.get().unwrap(), 2);
+    assert_eq!(*old, 1);
+    assert!(cell.init(&OLD).is_err());
+}
+#[test]
+fn test_init_panic_resets_state() {
+    static DATA: u32 = 7;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let _ = cell.init_internal(|| panic!("initializer panicked."));
+    }));
+    assert!(result.is_err());
+    assert_eq!(cell.state(), crate::OnceInitState::UNINITIALIZED);
+    assert!(matches!(cell.get(), Err(OnceInitError::DataUninitialized)));
+    cell.init(&DATA).unwrap();
+    assert_eq!(*cell.get().unwrap(), 7);
+}
+#[test]
+fn test_raw_round_trip() {
+    static DATA: u32 = 3;
+    let cell = OnceInit::new(&DATA);
+    let (state, ptr) = cell.as_raw();
+    assert_eq!(state, crate::INITIALIZED);
+    assert!(core::ptr::eq(ptr, &DATA));
+    let adopted = unsafe { OnceInit::from_raw(state, ptr) };
+    assert_eq!(*adopted.get().unwrap(), 3);
+
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let (state, ptr) = cell.as_raw();
+    assert_eq!(state, crate::UNINITIALIZED);
+    assert!(ptr.is_null());
+    let adopted = unsafe { OnceInit::from_raw(state, ptr) };
+    assert!(adopted.get().is_err());
+    adopted.init(&DATA).unwrap();
+
+    // 不来自 `as_raw` 的状态值在调试构建中触发断言。
+    #[cfg(debug_assertions)]
+    assert!(std::panic::catch_unwind(|| unsafe {
+        OnceInit::<u32>::from_raw(crate::INITIALIZING, core::ptr::null())
+    })
+    .is_err());
+}
+#[test]
+fn test_get_or_init_boxed() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static CELL: OnceInit<Vec<u32>> = OnceInit::uninit();
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            std::thread::spawn(|| {
+                CELL.get_or_init_boxed(|| {
+                    CALLS.fetch_add(1, Ordering::SeqCst);
+                    Box::new(vec![1, 2, 3])
+                })
+            })
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    let first = CELL.get().unwrap();
+    assert!(results.iter().all(|r| core::ptr::eq(*r, first)));
+    assert_eq!(first, &[1, 2, 3]);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+#[test]
+fn test_try_init_boxed_take() {
+    let cell: OnceInit<str> = OnceInit::uninit();
+    let data = cell.try_init_boxed_take("first".into()).unwrap();
+    assert_eq!(data, "first");
+    let rejected = cell.try_init_boxed_take("second".into()).unwrap_err();
+    assert_eq!(&*rejected, "second");
+    assert_eq!(cell.get().unwrap(), "first");
+}
+#[test]
+fn test_repr_c_layout() {
+    use core::mem::{offset_of, size_of};
+    assert_eq!(offset_of!(OnceInit<u64>, state), 0);
+    assert_eq!(offset_of!(OnceInit<u64>, data), size_of::<usize>());
+    assert_eq!(size_of::<OnceInit<u64>>(), 2 * size_of::<usize>());
+}
+mod ffi {
+    use crate::OnceInit;
+    #[repr(C)]
+    pub struct Device {
+        pub id: u32,
+    }
+    pub static DEVICE: OnceInit<Device> = OnceInit::uninit();
+    crate::extern_accessors!(DEVICE: Device => onceinit_test_device_get, onceinit_test_device_init);
+}
+#[test]
+fn test_extern_accessors() {
+    use ffi::*;
+    static DATA: Device = Device { id: 9 };
+    assert!(onceinit_test_device_get().is_null());
+    assert_eq!(unsafe { onceinit_test_device_init(&DATA) }, 0);
+    assert!(core::ptr::eq(onceinit_test_device_get(), &DATA));
+    assert_eq!(
+        unsafe { onceinit_test_device_init(&DATA) },
+        OnceInitError::DataInitialized.code()
+    );
+}
+#[cfg(feature = "process-singleton")]
+mod process_singleton {
+    use super::log::Logger;
+    mod host {
+        use super::Logger;
+        crate::process_singleton! {
+            pub fn logger() -> dyn Logger = "onceinit_test_logger_anchor";
+        }
+    }
+    mod plugin_a {
+        use super::Logger;
+        crate::process_singleton! {
+            pub unsafe fn logger() -> dyn Logger = extern "onceinit_test_logger_anchor";
+        }
+    }
+    mod plugin_b {
+        use super::Logger;
+        crate::process_singleton! {
+            pub unsafe fn logger() -> dyn Logger = extern "onceinit_test_logger_anchor";
+        }
+    }
+    #[test]
+    fn test_process_singleton() {
+        static LOGGER: super::a_logger::ALogger = super::a_logger::ALogger;
+        // 同一测试二进制中只有一处定义锚点，且各处声明的类型相同。
+        let (a, b) = unsafe { (plugin_a::logger(), plugin_b::logger()) };
+        assert!(a.get().is_err());
+        b.init(&LOGGER).unwrap();
+        assert!(core::ptr::eq(a, host::logger()));
+        assert!(core::ptr::eq(b, host::logger()));
+        host::logger()
+            .get()
+            .unwrap()
+            .log("shared through the anchor.");
+    }
+}
+#[test]
+fn test_slice_as_slice_and_iter() {
+    static TABLE: [u8; 3] = [1, 2, 3];
+    let cell: OnceInit<[u8]> = OnceInit::uninit();
+    assert!(matches!(
+        cell.as_slice(),
+        Err(OnceInitError::DataUninitialized)
+    ));
+    assert_eq!(cell.iter().count(), 0);
+    cell.init(&TABLE).unwrap();
+    assert_eq!(cell.as_slice().unwrap(), &[1, 2, 3]);
+    assert_eq!(cell.iter().copied().sum::<u8>(), 6);
+}
+#[test]
+fn test_expect_uninit() {
+    static DATA: u32 = 0;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    cell.expect_uninit("cell should be untouched.");
+    cell.init(&DATA).unwrap();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        cell.expect_uninit("cell initialized too early.")
+    }));
+    let msg = result.unwrap_err();
+    assert_eq!(
+        msg.downcast_ref::<String>().unwrap(),
+        "cell initialized too early."
+    );
+}
+#[test]
+fn test_with_timeout() {
+    use core::sync::atomic::Ordering;
+    use std::time::Duration;
+    static DATA: u32 = 5;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert!(matches!(
+        cell.with_timeout(Duration::from_millis(10)),
+        Err(OnceInitError::DataUninitialized)
+    ));
+    // 模拟卡住的初始化。
+    cell.state.store(crate::INITIALIZING, Ordering::SeqCst);
+    let err = cell.with_timeout(Duration::from_millis(10)).unwrap_err();
+    assert!(matches!(err, OnceInitError::TimedOut));
+    #[cfg(not(feature = "locale"))]
+    assert_eq!(err.to_string(), "timed out waiting for initialization.");
+    cell.state.store(crate::UNINITIALIZED, Ordering::SeqCst);
+    cell.init(&DATA).unwrap();
+    assert_eq!(*cell.with_timeout(Duration::ZERO).unwrap(), 5);
+    // 挂起等待的线程在初始化结束时被唤醒，而不是等到超时。
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    cell.state.store(crate::INITIALIZING, Ordering::SeqCst);
+    let start = std::time::Instant::now();
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            std::thread::sleep(Duration::from_millis(20));
+            unsafe { *cell.data.get() = Some(&DATA) }
+            cell.publish(crate::INITIALIZED);
+        });
+        assert_eq!(*cell.with_timeout(Duration::from_secs(60)).unwrap(), 5);
+    });
+    assert!(start.elapsed() < Duration::from_secs(30));
+}
+#[test]
+fn test_get_with_backoff() {
+    use std::time::Duration;
+    static DATA: u32 = 6;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert!(matches!(
+        cell.get_with_backoff(10, true),
+        Err(OnceInitError::DataUninitialized)
+    ));
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            cell.init_fn(|| {
+                std::thread::sleep(Duration::from_millis(20));
+                &DATA
+            })
+        });
+        while cell.state.load(core::sync::atomic::Ordering::Acquire) != crate::INITIALIZING {
+            std::hint::spin_loop();
+        }
+        assert_eq!(*cell.get_with_backoff(10, true).unwrap(), 6);
+    });
+    assert_eq!(*cell.get_with_backoff(0, false).unwrap(), 6);
+}
+#[test]
+fn test_get_downcast() {
+    use crate::DowncastError;
+    use core::any::Any;
+    struct AppContext {
+        name: &'static str,
+    }
+    #[derive(Debug)]
+    struct OtherContext;
+    let cell: OnceInit<dyn Any + Send + Sync> = OnceInit::uninit();
+    assert!(matches!(
+        cell.get_downcast::<AppContext>(),
+        Err(DowncastError::Uninitialized { .. })
+    ));
+    cell.init_boxed_any(Box::new(AppContext { name: "app" }))
+        .unwrap();
+    assert_eq!(cell.get_downcast::<AppContext>().unwrap().name, "app");
+    let err = cell.get_downcast::<OtherContext>().unwrap_err();
+    assert!(
+        matches!(err, DowncastError::WrongType { expected, actual, .. } if expected.ends_with("OtherContext") && actual == core::any::TypeId::of::<AppContext>())
+    );
+
+    let cell: OnceInit<dyn Any + Send + Sync> = OnceInit::uninit();
+    cell.init_boxed_any(Box::new(OtherContext)).unwrap();
+    assert!(cell.get_downcast::<OtherContext>().is_ok());
+    assert!(cell.get_downcast::<AppContext>().is_err());
+}
+#[test]
+fn test_poll() {
+    use core::{
+        sync::atomic::{AtomicUsize, Ordering},
+        task::{Context, Poll, Waker},
+    };
+    use std::{sync::Arc, task::Wake};
+    struct CountingWaker(AtomicUsize);
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    static DATA: u32 = 11;
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert!(matches!(
+        cell.poll(&mut cx),
+        Poll::Ready(Err(OnceInitError::DataUninitialized))
+    ));
+    // 模拟正在进行的初始化。
+    cell.state.store(crate::INITIALIZING, Ordering::SeqCst);
+    assert!(cell.poll(&mut cx).is_pending());
+    assert!(cell.poll(&mut cx).is_pending());
+    unsafe { *cell.data.get() = Some(&DATA) }
+    cell.publish(crate::INITIALIZED);
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    assert!(matches!(cell.poll(&mut cx), Poll::Ready(Ok(&11))));
+}
+#[test]
+fn test_str() {
+    let cell: OnceInit<str> = OnceInit::uninit();
+    assert!(cell.as_str().is_err());
+    assert_eq!(&*cell, "");
+    assert_eq!(cell.get_or_default(), "");
+    assert_eq!(cell.to_string(), "<uninit>");
+    cell.init_string(String::from("app")).unwrap();
+    assert_eq!(cell.as_str().unwrap(), "app");
+    assert_eq!(&*cell, "app");
+    assert_eq!(cell.to_string(), "app");
+    assert_eq!(
+        cell.init_string(String::from("other")).unwrap_err(),
+        "other"
+    );
+    assert!(cell.init_str("static").is_err());
+
+    let cell: OnceInit<str> = OnceInit::uninit();
+    cell.init_str("static").unwrap();
+    assert_eq!(cell.as_str().unwrap(), "static");
+}
+#[test]
+fn test_get_or_set() {
+    static FIRST: u32 = 1;
+    static SECOND: u32 = 2;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let (data, set) = cell.get_or_set(&FIRST);
+    assert!(set);
+    assert!(core::ptr::eq(data, &FIRST));
+    let (data, set) = cell.get_or_set(&SECOND);
+    assert!(!set);
+    assert!(core::ptr::eq(data, &FIRST));
+}
+#[test]
+fn test_lazy() {
+    use crate::Lazy;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    static ANSWER: Lazy<u32> = Lazy::new(|| {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        &42
+    });
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+    assert_eq!(*ANSWER, 42);
+    assert_eq!(*Lazy::force(&ANSWER), 42);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(format!("{ANSWER:?}"), "Lazy(OnceInit(42))");
+}
+#[test]
+fn test_init_cow() {
+    use std::borrow::Cow;
+    let cell: OnceInit<str> = OnceInit::uninit();
+    cell.init_cow(Cow::Borrowed("default")).unwrap();
+    assert_eq!(cell.as_str().unwrap(), "default");
+    let rejected = cell.init_cow(Cow::Owned(String::from("file"))).unwrap_err();
+    assert!(matches!(rejected, Cow::Owned(s) if s == "file"));
+    assert!(matches!(
+        cell.init_cow(Cow::Borrowed("again")),
+        Err(Cow::Borrowed("again"))
+    ));
+
+    let cell: OnceInit<str> = OnceInit::uninit();
+    cell.init_cow(Cow::Owned(String::from("file"))).unwrap();
+    assert_eq!(cell.as_str().unwrap(), "file");
+
+    let cell: OnceInit<[u8]> = OnceInit::uninit();
+    cell.init_cow(Cow::Owned(vec![1, 2])).unwrap();
+    assert_eq!(cell.as_slice().unwrap(), &[1, 2]);
+    let rejected = cell.init_cow(Cow::Owned(vec![3])).unwrap_err();
+    assert!(matches!(rejected, Cow::Owned(v) if v == [3]));
+    static BORROWED: [u8; 1] = [4];
+    assert!(cell.init_cow(Cow::Borrowed(&BORROWED)).is_err());
+}
+#[test]
+fn test_init_with_boxed_default() {
+    let cell: OnceInit<Vec<u32>> = OnceInit::uninit();
+    let first = cell.init_with_boxed_default();
+    assert!(first.is_empty());
+    assert!(core::ptr::eq(first, cell.init_with_boxed_default()));
+    assert!(cell.init_boxed(Box::new(vec![1])).is_err());
+    assert!(matches!(
+        cell.init_default(),
+        Err(OnceInitError::DataInitialized)
+    ));
+
+    let cell: OnceInit<String> = OnceInit::uninit();
+    cell.init_default().unwrap();
+    assert_eq!(cell.get().unwrap(), "");
+}
+#[test]
+fn test_init_vec() {
+    let cell: OnceInit<[u32]> = OnceInit::uninit();
+    assert_eq!(cell.len(), None);
+    assert_eq!(cell.is_empty(), None);
+    cell.init_vec(vec![1, 2, 3]).unwrap();
+    assert_eq!(cell.len(), Some(3));
+    assert_eq!(cell.is_empty(), Some(false));
+    assert_eq!(cell.init_vec(vec![4]).unwrap_err(), [4]);
+    assert_eq!(cell.as_slice().ok(), Some(&[1, 2, 3][..]));
+}
+#[test]
+fn test_slice_table() {
+    #[derive(Debug, PartialEq)]
+    struct Entry(u8);
+    static ENTRIES: [Entry; 2] = [Entry(1), Entry(2)];
+    let table: OnceInit<[Entry]> = OnceInit::uninit();
+    assert!(table.get_or_default().is_empty());
+    assert_eq!(table.iter().count(), 0);
+    table.init_slice(&ENTRIES).unwrap();
+    assert_eq!(table.len(), Some(2));
+    assert_eq!(table.iter().collect::<Vec<_>>(), [&Entry(1), &Entry(2)]);
+    assert_eq!(table.get_or_default(), &ENTRIES);
+
+    let empty: OnceInit<[Entry]> = OnceInit::uninit();
+    empty.init_vec(Vec::new()).unwrap();
+    assert_eq!(empty.is_empty(), Some(true));
+    assert_eq!(empty.iter().count(), 0);
+}
+#[test]
+fn test_slice_index() {
+    static STATES: [u8; 3] = [10, 20, 30];
+    static TABLE: OnceInit<[u8]> = OnceInit::uninit();
+    let result = std::panic::catch_unwind(|| TABLE[0]);
+    assert!(result.is_err());
+    assert!(TABLE[..].is_empty());
+    TABLE.init_slice(&STATES).unwrap();
+    assert_eq!(TABLE[1], 20);
+    assert_eq!(&TABLE[1..], &[20, 30]);
+    let mut sum = 0;
+    for state in &TABLE {
+        sum += *state as u32;
+    }
+    assert_eq!(sum, 60);
+}
+#[test]
+fn test_state_eq_and_display() {
+    use crate::OnceInitState;
+    static DATA: u32 = 0;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let before = cell.state();
+    assert_eq!(before, OnceInitState::UNINITIALIZED);
+    assert_eq!(before.to_string(), "uninitialized");
+    cell.init(&DATA).unwrap();
+    assert_ne!(cell.state(), before);
+    assert_eq!(cell.state().to_string(), "initialized");
+}
+#[test]
+fn test_as_ptr() {
+    static DATA: u64 = 8;
+    let cell: OnceInit<u64> = OnceInit::uninit();
+    assert!(cell.as_ptr().is_null());
+    cell.init(&DATA).unwrap();
+    assert!(core::ptr::eq(cell.as_ptr(), &DATA));
+    assert_eq!(unsafe { *cell.as_ptr() }, 8);
+}
+#[test]
+fn test_init_boxed_with_contention() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Barrier,
+    };
+    const THREADS: usize = 8;
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    let cell: OnceInit<[u8]> = OnceInit::uninit();
+    let barrier = Barrier::new(THREADS);
+    let successes = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                s.spawn(|| {
+                    barrier.wait();
+                    cell.init_boxed_with(|| {
+                        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+                        vec![0; 1024].into_boxed_slice()
+                    })
+                    .is_ok()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|ok| *ok)
+            .count()
+    });
+    assert_eq!(successes, 1);
+    // 使用 `init_boxed` 时每个线程都会分配一次，而这里只有获胜的线程分配。
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), 1);
+    assert_eq!(cell.len(), Some(1024));
+}
+#[test]
+fn test_wait_initialized() {
+    use std::time::Duration;
+    static DATA: u32 = 21;
+    static CELL: OnceInit<u32> = OnceInit::uninit();
+    let waiter = std::thread::spawn(|| *CELL.wait_initialized());
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(!waiter.is_finished());
+    CELL.init(&DATA).unwrap();
+    assert_eq!(waiter.join().unwrap(), 21);
+    assert_eq!(*CELL.wait_initialized(), 21);
+}
+#[test]
+fn test_path() {
+    use std::{
+        ffi::{OsStr, OsString},
+        path::{Path, PathBuf},
+    };
+    let dir: OnceInit<Path> = OnceInit::uninit();
+    assert_eq!(&*dir, Path::new(""));
+    assert_eq!(dir.components().count(), 0);
+    dir.init_pathbuf(PathBuf::from("/var/lib/app")).unwrap();
+    assert_eq!(&*dir, Path::new("/var/lib/app"));
+    assert_eq!(
+        dir.init_pathbuf(PathBuf::from("/tmp")).unwrap_err(),
+        PathBuf::from("/tmp")
+    );
+    assert!(dir.init_path(Path::new("/etc")).is_err());
+
+    let name: OnceInit<OsStr> = OnceInit::uninit();
+    assert!(name.get_or_default().is_empty());
+    name.init_os_string(OsString::from("app")).unwrap();
+    assert_eq!(name.get().unwrap(), "app");
+    assert_eq!(
+        name.init_os_string(OsString::from("other")).unwrap_err(),
+        "other"
+    );
+    assert!(name.init_os_str(OsStr::new("x")).is_err());
+}
+#[cfg(feature = "test-util")]
+#[test]
+fn test_force_state() {
+    use crate::{OnceInitState, INITIALIZING, UNINITIALIZED};
+    use std::time::Duration;
+    static DATA: u32 = 4;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    unsafe { cell.force_raw_state(INITIALIZING) };
+    assert!(matches!(
+        cell.with_timeout(Duration::from_millis(5)),
+        Err(OnceInitError::TimedOut)
+    ));
+    unsafe { cell.force_raw_state(UNINITIALIZED) };
+    cell.init(&DATA).unwrap();
+    unsafe { cell.force_state(OnceInitState::UNINITIALIZED) };
+    assert!(cell.get().is_err());
+    unsafe { cell.force_state(OnceInitState::INITIALIZED) };
+    assert_eq!(*cell.get().unwrap(), 4);
+}
+#[test]
+fn test_cstr() {
+    use std::ffi::{CStr, CString};
+    let name: OnceInit<CStr> = OnceInit::uninit();
+    let ptr = name.as_ptr();
+    assert!(!ptr.is_null());
+    assert_eq!(unsafe { *ptr }, 0);
+    assert_eq!(name.as_ptr(), ptr);
+    assert!(name.get_or_default().is_empty());
+    name.init_cstring(CString::new("dev0").unwrap()).unwrap();
+    let ptr = name.as_ptr();
+    assert_eq!(unsafe { CStr::from_ptr(ptr) }, c"dev0");
+    assert_eq!(unsafe { *ptr.add(4) }, 0);
+    assert_eq!(
+        name.init_cstring(CString::new("dev1").unwrap())
+            .unwrap_err(),
+        CString::new("dev1").unwrap()
+    );
+    assert!(name.init_cstr(c"dev2").is_err());
+
+    let name: OnceInit<CStr> = OnceInit::uninit();
+    name.init_cstr(c"static").unwrap();
+    assert_eq!(name.get().unwrap().to_bytes_with_nul(), b"static\0");
+}
+#[test]
+fn test_is_initialized() {
+    static DATA: u32 = 0;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert!(!cell.is_initialized());
+    cell.init(&DATA).unwrap();
+    assert!(cell.is_initialized());
+}
+#[test]
+fn test_eq_and_hash() {
+    use std::collections::HashSet;
+    static A: u32 = 1;
+    static B: u32 = 1;
+    static C: u32 = 2;
+    let uninit_a: OnceInit<u32> = OnceInit::uninit();
+    let uninit_b: OnceInit<u32> = OnceInit::uninit();
+    // 均未初始化时相等。
+    assert_eq!(uninit_a, uninit_b);
+    assert!(uninit_a.ptr_eq(&uninit_b));
+    // 已初始化与未初始化的总是不相等。
+    let a = OnceInit::new(&A);
+    assert_ne!(a, uninit_a);
+    assert!(!a.ptr_eq(&uninit_a));
+    // 已初始化时比较内部数据。
+    let b = OnceInit::new(&B);
+    assert_eq!(a, b);
+    assert!(!a.ptr_eq(&b));
+    assert!(a.ptr_eq(&OnceInit::new(&A)));
+    assert_ne!(a, OnceInit::new(&C));
+
+    // 集合中的元素不会再被初始化，哈希值不会改变。
+    #[allow(clippy::mutable_key_type)]
+    let set: HashSet<_> = [a, b, uninit_a, uninit_b, OnceInit::new(&C)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 3);
+
+    let logger: OnceInit<dyn log::Logger> = OnceInit::uninit();
+    static LOGGER: a_logger::ALogger = a_logger::ALogger;
+    logger.init(&LOGGER).unwrap();
+    assert!(logger.ptr_eq(&OnceInit::new(&LOGGER)));
+}
+#[test]
+fn test_init_token() {
+    static DATA: u32 = 12;
+    static CELL: OnceInit<u32> = OnceInit::uninit();
+    assert!(CELL.token().is_none());
+    let token = CELL.init_with_token(&DATA).unwrap();
+    assert_eq!(*CELL.get_with_token(token), 12);
+    assert_eq!(*token.get(), 12);
+    assert!(CELL.init_with_token(&DATA).is_err());
+    let token = CELL.token().unwrap();
+    assert_eq!(*CELL.get_with_token(token), 12);
+
+    let other = OnceInit::new(&DATA);
+    let result =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| other.get_with_token(token)));
+    assert!(result.is_err());
+}
+#[test]
+fn test_init_boxed_reporting() {
+    use crate::OnceInitState;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let (state, rejected) = cell.init_boxed_reporting(Box::new(1));
+    assert_eq!(state, OnceInitState::UNINITIALIZED);
+    assert!(rejected.is_none());
+    let (state, rejected) = cell.init_boxed_reporting(Box::new(2));
+    assert_eq!(state, OnceInitState::INITIALIZED);
+    assert_eq!(rejected.as_deref(), Some(&2));
+    assert_eq!(*cell.get().unwrap(), 1);
+
+    // 模拟其他线程正在初始化。
+    static DATA: u32 = 3;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    cell.state
+        .store(crate::INITIALIZING, core::sync::atomic::Ordering::SeqCst);
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            unsafe { *cell.data.get() = Some(&DATA) }
+            cell.publish(crate::INITIALIZED);
+        });
+        let (state, rejected) = cell.init_boxed_reporting(Box::new(4));
+        assert_eq!(state, OnceInitState::INITIALIZING);
+        assert_eq!(rejected.as_deref(), Some(&4));
+    });
+    assert_eq!(*cell.get().unwrap(), 3);
+}
+#[test]
+fn test_eq_value() {
+    #[derive(Debug, PartialEq)]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+    static FAST: Mode = Mode::Fast;
+    let mode: OnceInit<Mode> = OnceInit::uninit();
+    assert_ne!(mode, Mode::Fast);
+    assert!(!mode.contains(&Mode::Slow));
+    mode.init(&FAST).unwrap();
+    assert_eq!(mode, Mode::Fast);
+    assert_ne!(mode, Mode::Slow);
+    assert!(mode.contains(&Mode::Fast));
+
+    // 未初始化时解引用得到默认值，但比较时仍与默认值不相等。
+    let name: OnceInit<str> = OnceInit::uninit();
+    assert_eq!(&*name, "");
+    assert!(!name.contains(""));
+    assert!(name != *"");
+    name.init_str("name").unwrap();
+    assert!(name.contains("name"));
+    assert!(name == *"name");
+}
+#[test]
+fn test_erase() {
+    static LOGGER: a_logger::ALogger = a_logger::ALogger;
+    static CONCRETE: OnceInit<a_logger::ALogger> = OnceInit::uninit();
+    let erased = CONCRETE.erase::<dyn log::Logger>(|logger| logger);
+    assert!(erased.get().is_err());
+    CONCRETE.init(&LOGGER).unwrap();
+    // 快照不会随原 `OnceInit` 一同初始化。
+    assert!(erased.get().is_err());
+    let erased = CONCRETE.erase::<dyn log::Logger>(|logger| logger);
+    assert!(core::ptr::addr_eq(erased.get().unwrap(), &LOGGER));
+}
+#[test]
+fn test_display() {
+    use std::fmt::Display;
+    static VERSION: u32 = 3;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert_eq!(cell.to_string(), "<uninit>");
+    cell.init(&VERSION).unwrap();
+    assert_eq!(format!("v{cell:>3}"), "v  3");
+
+    let cell: OnceInit<dyn Display + Sync> = OnceInit::uninit();
+    assert_eq!(cell.to_string(), "<uninit>");
+    cell.init(&VERSION).unwrap();
+    assert_eq!(cell.to_string(), "3");
+    // 正在初始化时，格式化不会等待。
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    cell.state
+        .store(crate::INITIALIZING, core::sync::atomic::Ordering::SeqCst);
+    assert_eq!(cell.to_string(), "<initializing>");
+}
+#[test]
+fn test_size() {
+    use core::mem::size_of;
+    assert_eq!(size_of::<OnceInit<u8>>(), 2 * size_of::<usize>());
+    assert_eq!(size_of::<OnceInit<str>>(), 3 * size_of::<usize>());
+    assert_eq!(
+        size_of::<OnceInit<dyn log::Logger>>(),
+        3 * size_of::<usize>()
+    );
+}
+#[test]
+fn test_poll_get() {
+    use core::{
+        sync::atomic::{AtomicUsize, Ordering},
+        task::{Context, Poll, Waker},
+    };
+    use std::{sync::Arc, task::Wake};
+    struct CountingWaker(AtomicUsize);
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    static DATA: u32 = 12;
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    // 未初始化时同样挂起，重复登记只会唤醒一次。
+    assert!(cell.poll_get(&mut cx).is_pending());
+    assert!(cell.poll_get(&mut cx).is_pending());
+    cell.init(&DATA).unwrap();
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    assert_eq!(cell.poll_get(&mut cx), Poll::Ready(&12));
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+}
+#[test]
+fn test_wait() {
+    use core::{
+        future::Future,
+        pin::pin,
+        sync::atomic::{AtomicUsize, Ordering},
+        task::{Context, Poll, Waker},
+    };
+    use std::{sync::Arc, task::Wake};
+    struct CountingWaker(AtomicUsize);
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+    static DATA: u32 = 13;
+    let first = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let second = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let first_waker = Waker::from(first.clone());
+    let second_waker = Waker::from(second.clone());
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let key = cell.waiter_key();
+    // 被丢弃时取消登记。
+    {
+        let mut wait = pin!(cell.wait());
+        assert!(wait
+            .as_mut()
+            .poll(&mut Context::from_waker(&first_waker))
+            .is_pending());
+        assert!(wait
+            .as_mut()
+            .poll(&mut Context::from_waker(&first_waker))
+            .is_pending());
+        assert_eq!(crate::waiter::count(key), 1);
+    }
+    assert_eq!(crate::waiter::count(key), 0);
+    // 同一任务中的两个 `Wait` 各自登记，丢弃其一不影响另一个；换用新的 `Waker` 时替换原有登记项。
+    let mut kept = pin!(cell.wait());
+    assert!(kept
+        .as_mut()
+        .poll(&mut Context::from_waker(&first_waker))
+        .is_pending());
+    {
+        let mut dropped = pin!(cell.wait());
+        assert!(dropped
+            .as_mut()
+            .poll(&mut Context::from_waker(&first_waker))
+            .is_pending());
+        assert_eq!(crate::waiter::count(key), 2);
+    }
+    assert!(kept
+        .as_mut()
+        .poll(&mut Context::from_waker(&second_waker))
+        .is_pending());
+    assert_eq!(crate::waiter::count(key), 1);
+    cell.init(&DATA).unwrap();
+    assert_eq!(first.0.load(Ordering::SeqCst), 0);
+    assert_eq!(second.0.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        kept.as_mut().poll(&mut Context::from_waker(&second_waker)),
+        Poll::Ready(&13)
+    );
+    assert_eq!(crate::waiter::count(key), 0);
+}
+#[test]
+fn test_downcast() {
+    use std::any::Any;
+    static NUMBER: u32 = 7;
+    static REGISTRY: OnceInit<dyn Any + Send + Sync> = OnceInit::uninit();
+    assert!(!REGISTRY.is::<u32>());
+    assert_eq!(REGISTRY.downcast::<u32>(), None);
+    REGISTRY.init(&NUMBER).unwrap();
+    assert!(REGISTRY.is::<u32>());
+    assert!(!REGISTRY.is::<i32>());
+    assert_eq!(REGISTRY.downcast::<u32>(), Some(&7));
+    assert_eq!(REGISTRY.downcast::<i32>(), None);
+
+    let local: OnceInit<dyn Any> = OnceInit::new(&NUMBER);
+    assert_eq!(local.downcast::<u32>(), Some(&7));
+}
+#[test]
+fn test_clone() {
+    static DATA: u32 = 5;
+    #[derive(Clone)]
+    struct Holder {
+        cell: OnceInit<u32>,
+    }
+    let holder = Holder {
+        cell: OnceInit::uninit(),
+    };
+    let uninit = holder.clone();
+    holder.cell.init(&DATA).unwrap();
+    assert!(uninit.cell.get().is_err());
+    let init = holder.clone();
+    assert!(init.cell.ptr_eq(&holder.cell));
+
+    // 正在初始化时等待其完成。
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    cell.state
+        .store(crate::INITIALIZING, core::sync::atomic::Ordering::SeqCst);
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            unsafe { *cell.data.get() = Some(&DATA) }
+            cell.publish(crate::INITIALIZED);
+        });
+        assert_eq!(*cell.clone().get().unwrap(), 5);
+    });
+}
+#[test]
+fn test_into_option() {
+    static DATA: u32 = 8;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let data: Option<&u32> = (&cell).into();
+    assert_eq!(data, None);
+    cell.init(&DATA).unwrap();
+    let Some(data): Option<&u32> = Option::from(&cell) else {
+        unreachable!()
+    };
+    assert_eq!(*data, 8);
+}
+#[test]
+fn test_scope_init() {
+    static A: u32 = 1;
+    static B: u32 = 2;
+    let mut cell: OnceInit<u32> = OnceInit::uninit();
+    {
+        let guard = cell.scope_init(&A);
+        assert_eq!(*guard, 1);
+    }
+    assert!(cell.get().is_err());
+    cell.init(&A).unwrap();
+    {
+        let guard = cell.scope_init(&B);
+        assert_eq!(*guard, 2);
+    }
+    assert_eq!(*cell.get().unwrap(), 1);
+}
+#[test]
+fn test_panicking() {
+    use crate::{PanicOnDoubleInit, PanicOnUninit, PanickingOnceInit};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    static DATA: u32 = 9;
+    let cell: PanicOnUninit<u32> = PanicOnUninit::uninit();
+    assert!(catch_unwind(AssertUnwindSafe(|| cell.get())).is_err());
+    cell.init(&DATA).unwrap();
+    assert_eq!(*cell.get(), 9);
+    assert!(cell.init(&DATA).is_err());
+    assert!(cell.is_initialized());
+    assert_eq!(*cell.reader().get().unwrap(), 9);
+
+    let cell: PanicOnDoubleInit<u32> = PanicOnDoubleInit::uninit();
+    assert!(cell.get().is_err());
+    cell.init(&DATA);
+    assert!(catch_unwind(AssertUnwindSafe(|| cell.init(&DATA))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| cell.init_boxed(Box::new(1)))).is_err());
+    assert_eq!(*cell.get().unwrap(), 9);
+
+    let cell: PanickingOnceInit<str, true, true> = PanickingOnceInit::new("both");
+    assert_eq!(cell.get(), "both");
+    assert!(catch_unwind(AssertUnwindSafe(|| cell.init("again"))).is_err());
+}
+#[test]
+fn test_once_lock() {
+    use crate::UninitGlobalHolder;
+    use std::sync::OnceLock;
+    static LOGGER: a_logger::ALogger = a_logger::ALogger;
+    let lock: OnceLock<&'static dyn log::Logger> = OnceLock::new();
+    let cell: OnceInit<dyn log::Logger> = lock.into();
+    assert!(cell.get().is_err());
+    assert!(cell.to_once_lock().get().is_none());
+
+    let lock: OnceLock<&'static dyn log::Logger> = OnceLock::new();
+    lock.set(&LOGGER).ok().unwrap();
+    let cell = OnceInit::from(lock);
+    assert!(cell.ptr_eq(&OnceInit::new(&LOGGER)));
+    assert!(core::ptr::addr_eq(
+        *cell.to_once_lock().get().unwrap(),
+        &LOGGER
+    ));
+
+    fn init_holder(holder: &impl UninitGlobalHolder<str>) -> bool {
+        holder.init("first").is_ok() && holder.init_boxed("second".into()).is_err()
+    }
+    let lock: OnceLock<&'static str> = OnceLock::new();
+    assert!(init_holder(&lock));
+    assert_eq!(*lock.get().unwrap(), "first");
+    let cell: OnceInit<str> = OnceInit::uninit();
+    assert!(init_holder(&cell));
+    assert_eq!(cell.get().unwrap(), "first");
+}
+#[test]
+fn test_init_fn() {
+    static DATA: u32 = 13;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let mut calls = 0;
+    cell.init_fn(|| {
+        calls += 1;
+        &DATA
+    })
+    .unwrap();
+    assert!(cell
+        .init_fn(|| {
+            calls += 1;
+            &DATA
+        })
+        .is_err());
+    assert_eq!(calls, 1);
+    assert_eq!(*cell.get().unwrap(), 13);
+}
+#[test]
+fn test_as_ref() {
+    use std::path::Path;
+    fn len(s: impl AsRef<str>) -> usize {
+        s.as_ref().len()
+    }
+    let name: OnceInit<str> = OnceInit::uninit();
+    assert_eq!(len(&name), 0);
+    name.init_str("name").unwrap();
+    assert_eq!(len(&name), 4);
+
+    static DIR: OnceInit<Path> = OnceInit::uninit();
+    DIR.init_path(Path::new(env!("CARGO_MANIFEST_DIR")))
+        .unwrap();
+    assert!(std::fs::metadata(&DIR).unwrap().is_dir());
+    assert!(std::fs::read_dir(&DIR).is_ok());
+}
+#[test]
+fn test_get_until() {
+    use std::time::{Duration, Instant};
+    static DATA: u32 = 22;
+    static CELL: OnceInit<u32> = OnceInit::uninit();
+    // 截止时间已过时不阻塞。
+    assert!(matches!(
+        CELL.get_until(Instant::now() - Duration::from_secs(1)),
+        Err(OnceInitError::TimedOut)
+    ));
+    let start = Instant::now();
+    assert!(matches!(
+        CELL.get_until(start + Duration::from_millis(20)),
+        Err(OnceInitError::TimedOut)
+    ));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    let waiter = std::thread::spawn(|| CELL.get_until(Instant::now() + Duration::from_secs(10)));
+    std::thread::sleep(Duration::from_millis(20));
+    CELL.init(&DATA).unwrap();
+    assert_eq!(*waiter.join().unwrap().unwrap(), 22);
+    assert_eq!(*CELL.get_until(Instant::now()).unwrap(), 22);
+}
+#[test]
+fn test_try_get_or_default() {
+    use core::sync::atomic::Ordering;
+    let cell: OnceInit<str> = OnceInit::uninit();
+    assert_eq!(cell.try_get_or_default(), "");
+    // 正在初始化时不等待。
+    cell.state.store(crate::INITIALIZING, Ordering::SeqCst);
+    assert_eq!(cell.try_get_or_default(), "");
+    cell.state.store(crate::UNINITIALIZED, Ordering::SeqCst);
+    cell.init_str("ready").unwrap();
+    assert_eq!(cell.try_get_or_default(), "ready");
+}
+#[test]
+fn test_once_lock_owned() {
+    use std::sync::OnceLock;
+    let cell = OnceInit::from_once_lock(OnceLock::<String>::new());
+    assert!(cell.get().is_err());
+    assert!(cell.as_once_lock().is_none());
+    assert!(OnceLock::<String>::from(cell).get().is_none());
+
+    let cell = OnceInit::from_once_lock(OnceLock::from(String::from("config")));
+    assert_eq!(cell.get().unwrap(), "config");
+    assert_eq!(cell.as_once_lock().unwrap().get().unwrap(), "config");
+    let lock: OnceLock<String> = cell.into();
+    assert_eq!(lock.into_inner().unwrap(), "config");
+}
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize() {
+    use crate::OnceInitState;
+    static PORTS: [u16; 2] = [80, 443];
+    let ports: OnceInit<[u16]> = OnceInit::uninit();
+    assert_eq!(serde_json::to_string(&ports).unwrap(), "null");
+    ports.init_slice(&PORTS).unwrap();
+    assert_eq!(serde_json::to_string(&ports).unwrap(), "[80,443]");
+    let name: OnceInit<str> = OnceInit::new("app");
+    assert_eq!(serde_json::to_string(&name).unwrap(), r#""app""#);
+    assert_eq!(
+        serde_json::to_string(&OnceInitState::INITIALIZED).unwrap(),
+        r#""INITIALIZED""#
+    );
+    assert_eq!(
+        serde_json::to_string(&OnceInitError::TimedOut).unwrap(),
+        r#""TimedOut""#
+    );
+}
+#[cfg(feature = "once_cell")]
+#[test]
+fn test_once_cell() {
+    use once_cell::sync::OnceCell;
+    let cell: OnceInit<String> = OnceCell::new().into();
+    assert!(cell.get().is_err());
+    assert!(OnceCell::<String>::from(cell).get().is_none());
+
+    let cell: OnceInit<String> = OnceCell::with_value(String::from("config")).into();
+    assert_eq!(cell.get().unwrap(), "config");
+    let cell: OnceCell<String> = cell.into();
+    assert_eq!(cell.get().unwrap(), "config");
+}
+#[test]
+fn test_custom_error() {
+    #[derive(Debug, PartialEq)]
+    enum ConfigError {
+        Invalid,
+        Init,
+    }
+    impl From<OnceInitError> for ConfigError {
+        fn from(_: OnceInitError) -> Self {
+            ConfigError::Init
+        }
+    }
+    static PORT: u16 = 0;
+    static OTHER_PORT: u16 = 8080;
+    let check = |port: &u16| {
+        if *port == 0 {
+            Err(ConfigError::Invalid)
+        } else {
+            Ok(())
+        }
+    };
+    let cell: OnceInit<u16> = OnceInit::uninit();
+    assert_eq!(cell.init_checked(&PORT, check), Err(ConfigError::Invalid));
+    assert!(cell.get().is_err());
+    assert_eq!(cell.init_checked(&OTHER_PORT, check), Ok(()));
+    assert_eq!(
+        cell.init_checked(&OTHER_PORT, check),
+        Err(ConfigError::Init)
+    );
+
+    let cell: OnceInit<u16> = OnceInit::uninit();
+    assert_eq!(
+        cell.get_or_try_init(|| Err(ConfigError::Invalid)),
+        Err(ConfigError::Invalid)
+    );
+    assert!(cell.get().is_err());
+    assert_eq!(
+        cell.get_or_try_init(|| Ok::<_, ConfigError>(&OTHER_PORT)),
+        Ok(&8080)
+    );
+    assert_eq!(
+        cell.get_or_try_init(|| Err(ConfigError::Invalid)),
+        Ok(&8080)
+    );
+}
+#[cfg(feature = "serde")]
+#[test]
+fn test_deserialize() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Config {
+        name: OnceInit<String>,
+        port: OnceInit<u16>,
+        #[serde(default = "OnceInit::uninit")]
+        workers: OnceInit<u32>,
+    }
+    let config: Config = serde_json::from_str(r#"{"name":"app","port":null}"#).unwrap();
+    assert_eq!(config.name.get().unwrap(), "app");
+    assert!(config.port.get().is_err());
+    assert!(config.workers.get().is_err());
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"name":"app","port":null,"workers":null}"#);
+    let config: Config = serde_json::from_str(&json).unwrap();
+    assert_eq!(config.name.get().unwrap(), "app");
+    assert!(config.port.get().is_err());
+}
+#[cfg(feature = "atomic-wait")]
+#[test]
+fn test_atomic_wait() {
+    use std::time::Duration;
+    static DATA: u32 = 23;
+    static CELL: OnceInit<u32> = OnceInit::uninit();
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            CELL.get_or_init(|| {
+                std::thread::sleep(Duration::from_millis(50));
+                &DATA
+            })
+        });
+        std::thread::sleep(Duration::from_millis(10));
+        let waiters: Vec<_> = (0..4).map(|_| s.spawn(|| *CELL.get().unwrap())).collect();
+        for waiter in waiters {
+            assert_eq!(waiter.join().unwrap(), 23);
+        }
+    });
+}
+#[cfg(feature = "defmt")]
+#[test]
+fn test_defmt() {
+    // 主机上没有 `defmt` 的日志后端，只检查实现是否存在。
+    fn assert_format<T: ?Sized + defmt::Format>() {}
+    assert_format::<crate::OnceInitState>();
+    assert_format::<OnceInitError>();
+    assert_format::<OnceInit<u32>>();
+    assert_format::<OnceInit<str>>();
+    assert_format::<OnceInit<[u8]>>();
+}
+#[test]
+fn test_get_or_init_racy() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Barrier,
+    };
+    static VALUES: [u32; 4] = [0, 1, 2, 3];
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let calls = AtomicUsize::new(0);
+    let barrier = Barrier::new(VALUES.len());
+    let results: Vec<&u32> = std::thread::scope(|s| {
+        let handles: Vec<_> = VALUES
+            .iter()
+            .map(|value| {
+                let (cell, calls, barrier) = (&cell, &calls, &barrier);
+                s.spawn(move || {
+                    barrier.wait();
+                    cell.get_or_init_racy(|| {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        value
+                    })
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    // 每个线程返回胜出的值，或在胜出者写入期间返回自己的值。
+    let winner = cell.get().unwrap();
+    assert!(results
+        .iter()
+        .zip(&VALUES)
+        .all(|(data, own)| core::ptr::eq(*data, winner) || core::ptr::eq(*data, own)));
+    assert!(calls.load(Ordering::SeqCst) >= 1);
+    // 初始化后不再调用 `f`.
+    cell.get_or_init_racy(|| unreachable!());
+
+    // 其他线程正在初始化时不等待，直接返回自己的值。
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    cell.state.store(crate::INITIALIZING, Ordering::SeqCst);
+    assert!(core::ptr::eq(
+        cell.get_or_init_racy(|| &VALUES[1]),
+        &VALUES[1]
+    ));
+    assert_eq!(cell.state.load(Ordering::SeqCst), crate::INITIALIZING);
+}
+#[cfg(feature = "ufmt")]
+#[test]
+fn test_ufmt() {
+    use crate::OnceInitState;
+    static DATA: u32 = 24;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let mut s = String::new();
+    ufmt::uwrite!(s, "{:?} {}", cell, cell).unwrap();
+    assert_eq!(s, "OnceInit(<uninit>) <uninit>");
+    cell.init(&DATA).unwrap();
+    s.clear();
+    ufmt::uwrite!(s, "{:?} {}", cell, cell).unwrap();
+    assert_eq!(s, format!("{cell:?} {cell}"));
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    cell.state
+        .store(crate::INITIALIZING, core::sync::atomic::Ordering::SeqCst);
+    s.clear();
+    ufmt::uwrite!(s, "{}", cell).unwrap();
+    assert_eq!(s, "<initializing>");
+    s.clear();
+    ufmt::uwrite!(
+        s,
+        "{} {:?}",
+        OnceInitState::INITIALIZED,
+        OnceInitError::TimedOut
+    )
+    .unwrap();
+    assert_eq!(s, "initialized TimedOut");
+}
+#[test]
+fn test_init_order() {
+    use crate::InitOrder;
+    static A: u32 = 1;
+    let (a, b, c) = (
+        OnceInit::<u32>::uninit(),
+        OnceInit::<u32>::uninit(),
+        OnceInit::<u32>::uninit(),
+    );
+    let mut order = InitOrder::new();
+    order.require_before(&a, "a");
+    order.require_before(&b, "b");
+    order.require_before(&c, "c");
+    assert_eq!(order.verify_all_initialized(), Err("a"));
+    order.assert_init_order(&a);
+    let message =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| order.assert_init_order(&c)))
+            .unwrap_err();
+    assert_eq!(
+        message.downcast_ref::<String>().unwrap(),
+        "`a` must be initialized before `c`."
+    );
+    a.init(&A).unwrap();
+    b.init(&A).unwrap();
+    order.assert_init_order(&c);
+    assert_eq!(order.verify_all_initialized(), Err("c"));
+    c.init(&A).unwrap();
+    assert_eq!(order.verify_all_initialized(), Ok(()));
+}
+#[test]
+fn test_as_deref() {
+    let name: OnceInit<String> = OnceInit::uninit();
+    assert!(name.as_deref().is_err());
+    name.init_boxed(Box::new(String::from("app"))).unwrap();
+    let s: &'static str = name.as_deref().unwrap();
+    assert_eq!(s, "app");
+
+    static DATA: u32 = 25;
+    static REF: &u32 = &DATA;
+    let cell: OnceInit<&'static u32> = OnceInit::new(&REF);
+    assert_eq!(*cell.as_deref().unwrap(), 25);
+}
+#[test]
+fn test_debug_initializing() {
+    use std::{sync::mpsc, time::Duration};
+    static DATA: u32 = 26;
+    static CELL: OnceInit<u32> = OnceInit::uninit();
+    assert_eq!(format!("{CELL:?}"), "OnceInit(<uninit>)");
+    let (started, wait_started) = mpsc::channel();
+    let (finish, wait_finish) = mpsc::channel::<()>();
+    let initializer = std::thread::spawn(move || {
+        CELL.get_or_init(|| {
+            started.send(()).unwrap();
+            wait_finish.recv().unwrap();
+            &DATA
+        })
+    });
+    wait_started.recv().unwrap();
+    // 初始化函数仍在执行，格式化不应等待。
+    let (done, wait_done) = mpsc::channel();
+    std::thread::spawn(move || done.send(format!("{CELL:?}")).unwrap());
+    assert_eq!(
+        wait_done.recv_timeout(Duration::from_secs(5)).unwrap(),
+        "OnceInit(<initializing>)"
+    );
+    finish.send(()).unwrap();
+    initializer.join().unwrap();
+    assert_eq!(format!("{CELL:?}"), "OnceInit(26)");
+}
+#[test]
+fn test_memory_usage() {
+    static NUMBERS: [u64; 4] = [1, 2, 3, 4];
+    let numbers: OnceInit<[u64]> = OnceInit::uninit();
+    assert_eq!(numbers.memory_usage(), None);
+    numbers.init_slice(&NUMBERS).unwrap();
+    assert_eq!(numbers.memory_usage(), Some(32));
+    assert_eq!(OnceInit::<str>::new("hello").memory_usage(), Some(5));
+    let logger: OnceInit<dyn log::Logger> = OnceInit::new(&a_logger::ALogger);
+    assert_eq!(logger.memory_usage(), Some(0));
+}
+#[test]
+fn test_init_from_env_parse() {
+    use crate::EnvInitError;
+    // 只读取 Cargo 运行测试时设置的环境变量，不修改进程环境。
+    let major: OnceInit<u32> = OnceInit::uninit();
+    assert!(matches!(
+        major.init_from_env_parse("ONCEINIT_TEST_MISSING"),
+        Err(EnvInitError::Var(_))
+    ));
+    assert!(matches!(
+        major.init_from_env_parse("CARGO_PKG_NAME"),
+        Err(EnvInitError::Parse(_))
+    ));
+    assert!(major.get().is_err());
+    major
+        .init_from_env_parse("CARGO_PKG_VERSION_MAJOR")
+        .unwrap();
+    assert_eq!(
+        *major.get().unwrap(),
+        env!("CARGO_PKG_VERSION_MAJOR").parse::<u32>().unwrap()
+    );
+    assert!(matches!(
+        major.init_from_env_parse("CARGO_PKG_VERSION_MAJOR"),
+        Err(EnvInitError::Init(OnceInitError::DataInitialized))
+    ));
+}
+#[test]
+fn test_unwind_safe() {
+    use std::panic::{catch_unwind, RefUnwindSafe, UnwindSafe};
+    fn assert_unwind_safe<T: ?Sized + UnwindSafe + RefUnwindSafe>() {}
+    assert_unwind_safe::<OnceInit<u32>>();
+    assert_unwind_safe::<OnceInit<str>>();
+    assert_unwind_safe::<OnceInit<[u8]>>();
+
+    static DATA: u32 = 27;
+    let cell = OnceInit::new(&DATA);
+    assert_eq!(*catch_unwind(|| cell.get()).unwrap().unwrap(), 27);
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert!(catch_unwind(|| cell.get_or_init(|| panic!())).is_err());
+    assert!(cell.get().is_err());
+}
+#[test]
+fn test_or() {
+    static PRIMARY: OnceInit<str> = OnceInit::uninit();
+    static SECONDARY: OnceInit<str> = OnceInit::uninit();
+    assert!(matches!(
+        PRIMARY.or(&SECONDARY),
+        Err(OnceInitError::DataUninitialized)
+    ));
+    SECONDARY.init_str("secondary").unwrap();
+    assert_eq!(PRIMARY.or(&SECONDARY).unwrap(), "secondary");
+    PRIMARY.init_str("primary").unwrap();
+    assert_eq!(PRIMARY.or(&SECONDARY).unwrap(), "primary");
+}
+#[test]
+fn test_once_init_ref() {
+    use crate::{OnceInitRef, OnceInitState};
+    let values = vec![1, 2, 3];
+    let cell: OnceInitRef<[i32]> = OnceInitRef::uninit();
+    assert_eq!(cell.state(), OnceInitState::UNINITIALIZED);
+    assert_eq!(format!("{cell:?}"), "OnceInitRef(<uninit>)");
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4).map(|_| s.spawn(|| cell.init(&values))).collect();
+        let successes = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(Result::is_ok)
+            .count();
+        assert_eq!(successes, 1);
+    });
+    assert_eq!(cell.get().unwrap(), [1, 2, 3]);
+    assert_eq!(cell.state(), OnceInitState::INITIALIZED);
+    assert_eq!(format!("{cell:?}"), "OnceInitRef([1, 2, 3])");
+    // 正在初始化时，格式化不会等待。
+    let cell: OnceInitRef<u32> = OnceInitRef::uninit();
+    cell.state
+        .store(crate::INITIALIZING, core::sync::atomic::Ordering::SeqCst);
+    assert_eq!(format!("{cell:?}"), "OnceInitRef(<initializing>)");
+}
+/// 在编译期断言 `$ty` 未实现 `$trait`: 若实现了，`some_item` 的调用将产生歧义而无法编译。
+macro_rules! assert_not_impl {
+    ($ty:ty: $trait:path) => {{
+        trait AmbiguousIfImpl<A> {
+            fn some_item() {}
+        }
+        impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+        impl<T: ?Sized + $trait> AmbiguousIfImpl<u8> for T {}
+        <$ty as AmbiguousIfImpl<_>>::some_item()
+    }};
+}
+#[test]
+fn test_auto_traits() {
+    use std::{cell::Cell, sync::MutexGuard};
+    fn assert_send_sync<T: ?Sized + Send + Sync>() {}
+    assert_send_sync::<OnceInit<u32>>();
+    assert_send_sync::<OnceInit<str>>();
+    assert_send_sync::<OnceInit<dyn log::Logger + Send + Sync>>();
+    // `MutexGuard` 是 `Sync` 而非 `Send` 的，`OnceInit` 只要求 `T: Sync`.
+    assert_send_sync::<OnceInit<MutexGuard<'static, u32>>>();
+    // `Cell` 不是 `Sync` 的，`OnceInit` 既不是 `Send` 也不是 `Sync` 的。
+    assert_not_impl!(OnceInit<Cell<u32>>: Sync);
+    assert_not_impl!(OnceInit<Cell<u32>>: Send);
+}
+#[cfg(feature = "test-util")]
+#[test]
+fn test_assert_macros() {
+    static DATA: u32 = 3;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    crate::assert_uninitialized!(cell);
+    let err = std::panic::catch_unwind(|| crate::assert_initialized!(cell)).unwrap_err();
+    let msg = err.downcast_ref::<String>().unwrap();
+    assert!(msg.contains(file!()) && msg.contains("`cell` is not initialized"));
+    cell.init(&DATA).unwrap();
+    crate::assert_initialized!(cell);
+    crate::assert_initialized_eq!(cell, 3);
+    assert!(std::panic::catch_unwind(|| crate::assert_initialized_eq!(cell, 4)).is_err());
+    // 断言失败时，`$cell` 同样只求值一次。
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    let evaluated = core::cell::Cell::new(0);
+    let initialized = || {
+        evaluated.set(evaluated.get() + 1);
+        &cell
+    };
+    let uninit: OnceInit<u32> = OnceInit::uninit();
+    let uninit = || {
+        evaluated.set(evaluated.get() + 1);
+        &uninit
+    };
+    assert!(catch_unwind(AssertUnwindSafe(|| crate::assert_initialized!(uninit()))).is_err());
+    assert!(
+        catch_unwind(AssertUnwindSafe(|| crate::assert_uninitialized!(
+            initialized()
+        )))
+        .is_err()
+    );
+    assert!(
+        catch_unwind(AssertUnwindSafe(|| crate::assert_initialized_eq!(
+            initialized(),
+            4
+        )))
+        .is_err()
+    );
+    assert_eq!(evaluated.get(), 3);
+}
+#[test]
+fn test_debug_state_str() {
+    use core::sync::atomic::Ordering;
+    static DATA: u32 = 1;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert_eq!(cell.debug_state_str(), "uninitialized");
+    cell.state.store(crate::INITIALIZING, Ordering::SeqCst);
+    assert_eq!(cell.debug_state_str(), "initializing");
+    cell.state.store(crate::UNINITIALIZED, Ordering::SeqCst);
+    cell.init(&DATA).unwrap();
+    assert_eq!(cell.debug_state_str(), "initialized");
+}
+#[test]
+fn test_send_once_init() {
+    use crate::SendOnceInit;
+    use std::cell::Cell;
+    fn assert_send<T: Send>() {}
+    assert_send::<SendOnceInit<Cell<u32>>>();
+    assert_not_impl!(SendOnceInit<Cell<u32>>: Sync);
+    assert_not_impl!(SendOnceInit<std::rc::Rc<u32>>: Send);
+    let cell: SendOnceInit<Cell<u32>> = SendOnceInit::uninit();
+    assert!(cell.get().is_err());
+    assert_eq!(format!("{cell:?}"), "SendOnceInit(<uninit>)");
+    cell.init_boxed(Box::new(Cell::new(1))).unwrap();
+    assert!(matches!(
+        cell.init_boxed(Box::new(Cell::new(2))),
+        Err(OnceInitError::DataInitialized)
+    ));
+    let cell = std::thread::spawn(move || {
+        cell.get().unwrap().set(3);
+        cell
+    })
+    .join()
+    .unwrap();
+    assert!(cell.is_initialized());
+    assert_eq!(cell.into_inner().unwrap().get(), 3);
+}
+#[test]
+fn test_primitive_static_default() {
+    use crate::StaticDefault;
+    let count: OnceInit<u32> = OnceInit::uninit();
+    assert_eq!(*count, 0);
+    assert_eq!(*count.get_or_default(), u32::default());
+    assert!(core::ptr::eq(count.get_or_default(), u32::static_default()));
+    let ratio: OnceInit<f64> = OnceInit::uninit();
+    assert_eq!(*ratio, 0.0);
+    assert!(!*OnceInit::<bool>::uninit());
+    assert_eq!(*OnceInit::<char>::uninit(), char::default());
+    assert_eq!(*OnceInit::<()>::uninit(), ());
+    assert!(core::ptr::eq(
+        i128::static_default(),
+        i128::static_default()
+    ));
+    static DATA: u32 = 8;
+    count.init(&DATA).unwrap();
+    assert_eq!(*count, 8);
+}
+#[test]
+fn test_trait_object_static_default() {
+    use core::fmt::{Debug, Display};
+    use std::error::Error;
+    static ERROR: OnceInit<dyn Error + Send + Sync> = OnceInit::uninit();
+    static DEBUG: OnceInit<dyn Debug + Send + Sync> = OnceInit::uninit();
+    static DISPLAY: OnceInit<dyn Display + Send + Sync> = OnceInit::uninit();
+    assert_eq!((*ERROR).to_string(), "<default>");
+    assert!(ERROR.source().is_none());
+    assert_eq!(format!("{:?}", &*DEBUG), "<default>");
+    assert_eq!((*DISPLAY).to_string(), "<default>");
+    static MESSAGE: &str = "ready";
+    DISPLAY.init(&MESSAGE).unwrap();
+    assert_eq!((*DISPLAY).to_string(), "ready");
+}
+#[test]
+fn test_once_init_const() {
+    use crate::OnceInitConst;
+    #[allow(clippy::declare_interior_mutable_const)]
+    const NAME: OnceInitConst<str> = OnceInitConst::new("const");
+    const LEN: usize = NAME.get_const().len();
+    let name = NAME;
+    static LIMIT: OnceInitConst<u32> = OnceInitConst::new(&3);
+    assert_eq!(LEN, 5);
+    assert_eq!(name.get().unwrap(), "const");
+    assert_eq!(*LIMIT.get_const(), 3);
+    assert!(LIMIT.init(&4).is_err());
+    assert_eq!(format!("{LIMIT:?}"), "OnceInitConst(3)");
+}
+#[test]
+fn test_new_coercion() {
+    use std::fmt::Display;
+    struct Name;
+    impl Display for Name {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("name")
+        }
+    }
+    static NAME: Name = Name;
+    static DISPLAY: OnceInit<dyn Display + Sync> = OnceInit::uninit();
+    let local = OnceInit::<dyn Display + Sync>::new(&NAME);
+    DISPLAY.init(&NAME).unwrap();
+    let boxed: OnceInit<dyn Display + Sync> = OnceInit::uninit();
+    boxed.init_boxed(Box::new(1u8)).unwrap();
+    assert_eq!(format!("{local} {DISPLAY} {boxed}"), "name name 1");
+}
+#[test]
+fn test_get_or_init_recover() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    static DATA: u32 = 9;
+    let calls = AtomicU32::new(0);
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let data = cell.get_or_init_recover(|| {
+        if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+            panic!("transient failure");
+        }
+        &DATA
+    });
+    assert_eq!((*data, calls.load(Ordering::SeqCst)), (9, 2));
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    calls.store(0, Ordering::SeqCst);
+    let result = std::panic::catch_unwind(|| {
+        cell.get_or_init_recover(|| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            panic!("permanent failure")
+        })
+    });
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), crate::RECOVER_ATTEMPTS);
+    assert!(cell.get().is_err());
+}
+#[test]
+fn test_iter_opt() {
+    static DATA: [u32; 2] = [1, 2];
+    let cell: OnceInit<[u32]> = OnceInit::uninit();
+    assert_eq!(cell.iter_opt().count(), 0);
+    cell.init(&DATA).unwrap();
+    let mut iter = cell.iter_opt();
+    assert!(core::ptr::eq(iter.next().unwrap(), &DATA[..]));
+    assert!(iter.next().is_none());
+    // 与切片的 `IntoIterator` 不同，产生的是整个切片。
+    assert_eq!((&cell).into_iter().count(), 2);
+    let total: u32 = cell.iter_opt().flatten().sum();
+    assert_eq!(total, 3);
+}
+#[test]
+fn test_map() {
+    static NAME: OnceInit<str> = OnceInit::uninit();
+    assert!(matches!(
+        NAME.map(str::len),
+        Err(OnceInitError::DataUninitialized)
+    ));
+    assert_eq!(NAME.map_or(0, str::len), 0);
+    assert_eq!(NAME.map_or_else(|| usize::MAX, str::len), usize::MAX);
+    NAME.init("onceinit").unwrap();
+    assert_eq!(NAME.map(str::len).unwrap(), 8);
+    assert_eq!(NAME.map_or(0, str::len), 8);
+    assert_eq!(
+        NAME.map_or_else(|| unreachable!("initialized"), str::len),
+        8
+    );
+    assert_eq!(
+        NAME.and_then(|s| Ok(s.starts_with("once"))).ok(),
+        Some(true)
+    );
+    assert!(matches!(
+        NAME.and_then(|_| Err::<(), _>(OnceInitError::TimedOut)),
+        Err(OnceInitError::TimedOut)
+    ));
+}
+#[test]
+fn test_take() {
+    use crate::SendOnceInit;
+    static DATA: u32 = 1;
+    let mut cell: OnceInit<u32> = OnceInit::uninit();
+    assert!(cell.take().is_none());
+    cell.init(&DATA).unwrap();
+    let data = cell.get().unwrap();
+    assert!(core::ptr::eq(cell.take().unwrap(), data));
+    assert!(cell.get().is_err());
+    cell.init(&2).unwrap();
+    assert_eq!((*data, *cell.get().unwrap()), (1, 2));
+    let mut owned: SendOnceInit<String> = SendOnceInit::uninit();
+    owned.init_boxed(Box::new("owned".into())).unwrap();
+    assert_eq!(*owned.take().unwrap(), "owned");
+    assert!(!owned.is_initialized());
+    owned.init_boxed(Box::new("again".into())).unwrap();
+}
+#[test]
+fn test_byte_len() {
+    let name: OnceInit<str> = OnceInit::uninit();
+    assert_eq!((name.byte_len(), name.len()), (None, 0));
+    name.init("名字").unwrap();
+    assert_eq!(name.byte_len(), Some(6));
+    let table: OnceInit<[u16]> = OnceInit::uninit();
+    assert_eq!(table.len(), None);
+    table.init(&[1, 2, 3]).unwrap();
+    assert_eq!(table.len(), Some(3));
+}
+#[cfg(feature = "nightly")]
+#[test]
+fn test_try_trait() {
+    static PORT: OnceInit<u16> = OnceInit::uninit();
+    fn port() -> Result<u16, OnceInitError> {
+        Ok(*PORT.clone()?)
+    }
+    fn port_cell() -> OnceInit<u16> {
+        let port = PORT.clone()?;
+        OnceInit::new(port)
+    }
+    assert!(matches!(port(), Err(OnceInitError::DataUninitialized)));
+    assert!(port_cell().get().is_err());
+    PORT.init(&8080).unwrap();
+    assert_eq!(port().unwrap(), 8080);
+    assert_eq!(*port_cell().get().unwrap(), 8080);
+}
+#[test]
+fn test_static_default_macro() {
+    mod facade {
+        pub trait Metrics: Sync {
+            fn name(&self) -> &'static str;
+        }
+        pub struct NopMetrics;
+        impl Metrics for NopMetrics {
+            fn name(&self) -> &'static str {
+                "nop"
+            }
+        }
+        crate::static_default!(dyn Metrics = NopMetrics);
+    }
+    struct Limits {
+        max: u32,
+        min: u32,
+    }
+    // 同一作用域中的多次调用不会因生成的静态变量而冲突。
+    crate::static_default! {
+        Limits = Limits { max: 8, min: 1 };
+    }
+    crate::static_default!(facade::NopMetrics = facade::NopMetrics);
+    use crate::StaticDefault;
+    use facade::Metrics;
+    let limits: OnceInit<Limits> = OnceInit::uninit();
+    assert_eq!((limits.max, limits.min), (8, 1));
+    assert!(core::ptr::eq(
+        limits.get_or_default(),
+        Limits::static_default()
+    ));
+    let metrics: OnceInit<dyn Metrics> = OnceInit::uninit();
+    assert_eq!(metrics.name(), "nop");
+    assert_eq!(facade::NopMetrics::static_default().name(), "nop");
+}
+#[test]
+fn test_try_map() {
+    use std::num::ParseIntError;
+    let port: OnceInit<str> = OnceInit::uninit();
+    assert_eq!(port.try_map(str::parse::<u16>), Ok(None));
+    assert_eq!(
+        port.try_map_flat("unset", |s| s.parse::<u16>().map_err(|_| "invalid")),
+        Err("unset")
+    );
+    port.init("8080").unwrap();
+    assert_eq!(port.try_map(str::parse::<u16>), Ok(Some(8080)));
+    assert_eq!(
+        port.try_map_flat("unset", |s| s.parse::<u16>().map_err(|_| "invalid")),
+        Ok(8080)
+    );
+    let bad: OnceInit<str> = OnceInit::new("port");
+    let err: Result<Option<u8>, ParseIntError> = bad.try_map(str::parse);
+    assert!(err.is_err());
+    assert_eq!(
+        bad.try_map_flat("unset", |s| s.parse::<u16>().map_err(|_| "invalid")),
+        Err("invalid")
+    );
+}
+#[test]
+fn test_reader() {
+    use crate::{OnceInitReader, OnceInitState};
+    static DATA: u32 = 5;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let reader = cell.reader();
+    let copy: OnceInitReader<'_, u32> = OnceInitReader::from(&cell);
+    assert!(!reader.is_initialized());
+    assert_eq!(copy.state(), OnceInitState::UNINITIALIZED);
+    assert_eq!(format!("{reader:?}"), "OnceInitReader(OnceInit(<uninit>))");
+    cell.init(&DATA).unwrap();
+    assert_eq!((*reader.get().unwrap(), *copy.get().unwrap()), (5, 5));
+    assert!(reader.is_initialized());
+}
+#[test]
+fn test_nop_default() {
+    pub trait Sink: Sync {
+        fn write(&self, data: &[u8], flush: bool);
+        fn flush(&self);
+        fn capacity(&self) -> usize;
+        fn label(&self) -> Option<&'static str>;
+    }
+    crate::nop_default! {
+        dyn Sink => NopSink {
+            fn write(&self, data: &[u8], flush: bool);
+            fn flush(&self);
+            fn capacity(&self) -> usize = 0;
+            fn label(&self) -> Option<&'static str> = None;
+        }
+    }
+    let sink: OnceInit<dyn Sink> = OnceInit::uninit();
+    sink.write(b"data", true);
+    sink.flush();
+    assert_eq!((sink.capacity(), sink.label()), (0, None));
+    NopSink.flush();
+}
+#[test]
+fn test_get_pinned() {
+    use core::marker::PhantomPinned;
+    struct Node {
+        value: u32,
+        _pin: PhantomPinned,
+    }
+    static NODE: Node = Node {
+        value: 4,
+        _pin: PhantomPinned,
+    };
+    let cell: OnceInit<Node> = OnceInit::uninit();
+    assert!(cell.get_pinned().is_err());
+    cell.init(&NODE).unwrap();
+    let pinned = cell.get_pinned().unwrap();
+    assert_eq!(pinned.value, 4);
+    assert!(core::ptr::eq(pinned.get_ref(), &NODE));
+}
+#[test]
+fn test_uninit_const() {
+    static CELLS: [OnceInit<u32>; 2] = [OnceInit::UNINIT; 2];
+    assert!(CELLS.iter().all(|cell| cell.get().is_err()));
+    CELLS[1].init(&1).unwrap();
+    assert!(CELLS[0].get().is_err());
+    // `Default` 以 `static_default` 初始化，而 `UNINIT` 总是未初始化的。
+    assert!(OnceInit::<u32>::default().is_initialized());
+    #[allow(deprecated)]
+    let deprecated = OnceInit::<u32>::DEFAULT;
+    assert!(!deprecated.is_initialized());
+}
+#[test]
+fn test_const_static_default() {
+    use crate::{ConstStaticDefault, StaticDefault};
+    struct Limits {
+        max: u32,
+    }
+    impl ConstStaticDefault for Limits {
+        const STATIC_DEFAULT: &'static Self = &Limits { max: 16 };
+    }
+    pub trait Clock: Sync {
+        fn now(&self) -> u64;
+    }
+    struct ZeroClock;
+    impl Clock for ZeroClock {
+        fn now(&self) -> u64 {
+            0
+        }
+    }
+    impl ConstStaticDefault for dyn Clock {
+        const STATIC_DEFAULT: &'static Self = &ZeroClock;
+    }
+    let limits: OnceInit<Limits> = OnceInit::uninit();
+    assert_eq!(limits.get_or_default().max, 16);
+    assert_eq!(limits.max, 16);
+    assert_eq!(Limits::static_default().max, 16);
+    let clock: OnceInit<dyn Clock> = OnceInit::uninit();
+    assert_eq!(clock.now(), 0);
+    assert_eq!(<str as ConstStaticDefault>::STATIC_DEFAULT, "");
+}
+#[cfg(feature = "diagnostics")]
+#[test]
+fn test_get_with_spin_count() {
+    use core::sync::atomic::Ordering;
+    use std::time::Duration;
+    static DATA: u32 = 2;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let (result, spins) = cell.get_with_spin_count();
+    assert!(result.is_err());
+    assert_eq!(spins, 0);
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            cell.init_fn(|| {
+                std::thread::sleep(Duration::from_millis(10));
+                &DATA
+            })
+        });
+        while cell.state.load(Ordering::Acquire) != crate::INITIALIZING {
+            std::hint::spin_loop();
+        }
+        let (result, spins) = cell.get_with_spin_count();
+        assert_eq!(*result.unwrap(), 2);
+        assert!(spins > 0);
+        assert!(crate::SPIN_COUNT_TOTAL.load(Ordering::Relaxed) >= spins);
+    });
+    assert_eq!(cell.get_with_spin_count().1, 0);
+}
+#[cfg(feature = "const-default")]
+#[test]
+fn test_static_default_from_const_default() {
+    use const_default::ConstDefault;
+    #[derive(ConstDefault)]
+    struct Config {
+        retries: u32,
+        name: Option<&'static str>,
+    }
+    #[derive(ConstDefault)]
+    struct Flags(bool);
+    crate::static_default!(Config, Flags);
+    let config: OnceInit<Config> = OnceInit::uninit();
+    assert_eq!((config.retries, config.name), (0, None));
+    assert!(!OnceInit::<Flags>::uninit().0);
+}
+#[test]
+fn test_init_or_get() {
+    static FIRST: u32 = 1;
+    static SECOND: u32 = 2;
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    assert!(core::ptr::eq(cell.init_or_get(&FIRST), &FIRST));
+    assert!(core::ptr::eq(cell.init_or_get(&SECOND), &FIRST));
+    let cell: OnceInit<u32> = OnceInit::uninit();
+    let winners: Vec<&'static u32> = std::thread::scope(|s| {
+        let handles: Vec<_> = [&FIRST, &SECOND]
+            .into_iter()
+            .map(|data| s.spawn(|| cell.init_or_get(data)))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert!(core::ptr::eq(winners[0], winners[1]));
+    assert!(core::ptr::eq(winners[0], cell.get().unwrap()));
+}
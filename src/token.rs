@@ -0,0 +1,86 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{OnceInit, OnceInitError};
+use core::fmt::Debug;
+
+/// # `InitToken`
+/// 证明某个 [`OnceInit`] 已被初始化的凭证。
+///
+/// 只能由保证初始化完成的操作取得，见 [`init_with_token`](OnceInit::init_with_token) 与
+/// [`token`](OnceInit::token). 持有凭证时，可通过 [`get_with_token`](OnceInit::get_with_token)
+/// 安全地取得内部数据，无需处理错误。
+///
+/// 凭证借用了签发它的 [`OnceInit`], 因此在凭证存活期间，该 [`OnceInit`] 不会被移动或销毁。
+/// 为了将凭证与签发它的 [`OnceInit`] 对应起来，凭证中保存了其地址，大小与指针相同，而非零大小类型。
+pub struct InitToken<'a, T: ?Sized + 'static> {
+    cell: &'a OnceInit<T>,
+}
+
+impl<T: ?Sized> Clone for InitToken<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized> Copy for InitToken<'_, T> {}
+impl<T: ?Sized> Debug for InitToken<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("InitToken")
+            .field(&(self.cell as *const OnceInit<T>))
+            .finish()
+    }
+}
+impl<T: ?Sized> InitToken<'_, T> {
+    /// 返回签发该凭证的 [`OnceInit`] 的内部数据。
+    #[inline]
+    pub fn get(self) -> &'static T {
+        unsafe { self.cell.get_unchecked() }
+    }
+}
+
+impl<T: ?Sized> OnceInit<T> {
+    /// 同 [`init`](Self::init), 成功时返回 [`InitToken`].
+    #[inline]
+    pub fn init_with_token(&self, data: &'static T) -> Result<InitToken<'_, T>, OnceInitError> {
+        self.init(data).map(|_| InitToken { cell: self })
+    }
+    /// 若已初始化，则返回 [`InitToken`], 否则返回 [`None`]. 正在初始化时会等待其完成。
+    #[inline]
+    pub fn token(&self) -> Option<InitToken<'_, T>> {
+        self.get().ok().map(|_| InitToken { cell: self })
+    }
+    /// 凭 [`InitToken`] 返回内部数据。
+    ///
+    /// # Panics
+    ///
+    /// `token` 不是由 `self` 签发时恐慌。
+    #[inline]
+    #[track_caller]
+    pub fn get_with_token(&self, token: InitToken<'_, T>) -> &'static T {
+        assert!(
+            core::ptr::eq(self, token.cell),
+            "the token was issued by a different `OnceInit`."
+        );
+        token.get()
+    }
+}
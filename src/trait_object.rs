@@ -0,0 +1,59 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 为常用 trait 对象实现 [`ConstStaticDefault`], 进而实现 [`StaticDefault`](crate::StaticDefault).
+
+use crate::ConstStaticDefault;
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+/// 常用 trait 对象的默认值，渲染为 `<default>`.
+struct Placeholder;
+
+impl Debug for Placeholder {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str("<default>")
+    }
+}
+impl Display for Placeholder {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str("<default>")
+    }
+}
+impl Error for Placeholder {}
+
+macro_rules! impl_const_static_default {
+    ($($ty:ty),* $(,)?) => {$(
+        /// 返回渲染为 `<default>` 的单元结构体。
+        impl ConstStaticDefault for $ty {
+            const STATIC_DEFAULT: &'static Self = &Placeholder;
+        }
+    )*};
+}
+
+impl_const_static_default! {
+    dyn Debug, dyn Debug + Send + Sync,
+    dyn Display, dyn Display + Send + Sync,
+    dyn Error, dyn Error + Send + Sync,
+}
@@ -0,0 +1,70 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 为 [`OnceInit`] 实现不稳定的 [`Try`], 需启用 `nightly` 特性。
+//!
+//! 残差类型为 `Result<Infallible, OnceInitError>`, 因此 `cell?` 可以在返回 `Result<_, E>`（`E: From<OnceInitError>`）
+//! 的函数中使用。由于孤儿规则，无法为该残差类型实现 `Residual`.
+
+use crate::{OnceInit, OnceInitError};
+use core::{
+    convert::Infallible,
+    ops::{ControlFlow, FromResidual, Try},
+};
+
+/// 已初始化时 `?` 得到 `&'static T`, 否则传播 [`OnceInitError`]. 正在初始化时会等待其完成。
+///
+/// [`Try::branch`] 需要获取所有权，因此静态变量须先 [`clone`](Clone::clone)（只复制引用）再使用 `?`.
+///
+/// ``` rust
+/// #![feature(try_trait_v2)]
+/// use onceinit::{OnceInit, OnceInitError};
+///
+/// static PORT: OnceInit<u16> = OnceInit::new(&8080);
+/// fn port() -> Result<u16, OnceInitError> {
+///     Ok(*PORT.clone()?)
+/// }
+/// assert_eq!(port().unwrap(), 8080);
+/// ```
+impl<T: ?Sized> Try for OnceInit<T> {
+    type Output = &'static T;
+    type Residual = Result<Infallible, OnceInitError>;
+
+    #[inline]
+    fn from_output(output: Self::Output) -> Self {
+        OnceInit::new(output)
+    }
+    #[inline]
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self.get() {
+            Ok(data) => ControlFlow::Continue(data),
+            Err(e) => ControlFlow::Break(Err(e)),
+        }
+    }
+}
+/// 使 `?` 可以在返回 [`OnceInit`] 的函数中使用：出错时返回未初始化的 [`OnceInit`].
+impl<T: ?Sized> FromResidual<Result<Infallible, OnceInitError>> for OnceInit<T> {
+    #[inline]
+    fn from_residual(_: Result<Infallible, OnceInitError>) -> Self {
+        OnceInit::uninit()
+    }
+}
@@ -0,0 +1,98 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! `ufmt` 支持，适用于不希望引入 `core::fmt` 的嵌入式环境。
+
+use crate::{OnceInit, OnceInitError, OnceInitState};
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+/// 与 [`Debug`](core::fmt::Debug) 的输出相同。
+impl uDebug for OnceInitState {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(match self {
+            OnceInitState::UNINITIALIZED => "UNINITIALIZED",
+            OnceInitState::INITIALIZING => "INITIALIZING",
+            OnceInitState::INITIALIZED => "INITIALIZED",
+        })
+    }
+}
+/// 与 [`Display`](core::fmt::Display) 的输出相同。
+impl uDisplay for OnceInitState {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(match self {
+            OnceInitState::UNINITIALIZED => "uninitialized",
+            OnceInitState::INITIALIZING => "initializing",
+            OnceInitState::INITIALIZED => "initialized",
+        })
+    }
+}
+
+/// 与 [`Debug`](core::fmt::Debug) 的输出相同。
+impl uDebug for OnceInitError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(match self {
+            OnceInitError::DataUninitialized => "DataUninitialized",
+            OnceInitError::DataInitialized => "DataInitialized",
+            OnceInitError::TimedOut => "TimedOut",
+        })
+    }
+}
+/// 与未启用 `locale` 特性时 [`Display`](core::fmt::Display) 的输出相同。
+impl uDisplay for OnceInitError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(match self {
+            OnceInitError::DataUninitialized => "data is uninitialized.",
+            OnceInitError::DataInitialized => "data has already been initialized.",
+            OnceInitError::TimedOut => "timed out waiting for initialization.",
+        })
+    }
+}
+
+/// 未初始化或正在初始化时的占位符，不带引号。
+struct Placeholder(OnceInitState);
+
+impl uDebug for Placeholder {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.0.placeholder())
+    }
+}
+
+/// 与 [`Debug`](core::fmt::Debug) 一致，不会等待正在进行的初始化。
+impl<T: ?Sized + uDebug> uDebug for OnceInit<T> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let mut d = f.debug_tuple("OnceInit")?;
+        match self.peek() {
+            Ok(data) => d.field(&data)?,
+            Err(state) => d.field(&Placeholder(state))?,
+        };
+        d.finish()
+    }
+}
+/// 与 [`Display`](core::fmt::Display) 一致，不会等待正在进行的初始化。
+impl<T: ?Sized + uDisplay> uDisplay for OnceInit<T> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match self.peek() {
+            Ok(data) => data.fmt(f),
+            Err(state) => f.write_str(state.placeholder()),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 等待初始化完成的 [`Future`].
+
+use crate::{waiter, OnceInit, INITIALIZED};
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::Ordering,
+    task::{Context, Poll},
+};
+
+/// # `Wait`
+/// 等待 [`OnceInit`] 初始化完成的 [`Future`], 由 [`OnceInit::wait`] 返回。
+///
+/// 与直接调用 [`poll_get`](OnceInit::poll_get) 不同，该类型记录自己的登记项：
+/// 重复轮询只会更新同一登记项，被丢弃时会取消登记，因此放弃等待不会在登记表中留下 [`Waker`](core::task::Waker).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Wait<'a, T: ?Sized + 'static> {
+    cell: &'a OnceInit<T>,
+    id: Option<usize>,
+}
+
+impl<T: ?Sized> OnceInit<T> {
+    /// 返回等待数据初始化完成的 [`Future`], 完成时产生内部数据。
+    ///
+    /// 同 [`poll_get`](Self::poll_get), 未初始化时同样会等待；初始化失败（初始化函数恐慌）后继续等待。
+    /// 不依赖特定的运行时，`no_std` 下需启用 `alloc` 特性。
+    ///
+    /// ``` rust
+    /// use onceinit::OnceInit;
+    ///
+    /// static CONFIG: OnceInit<str> = OnceInit::uninit();
+    ///
+    /// async fn config_len() -> usize {
+    ///     CONFIG.wait().await.len()
+    /// }
+    /// ```
+    #[inline]
+    pub fn wait(&self) -> Wait<'_, T> {
+        Wait {
+            cell: self,
+            id: None,
+        }
+    }
+}
+
+impl<T: ?Sized> Future for Wait<'_, T> {
+    type Output = &'static T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let cell = self.cell;
+        if cell.state.load(Ordering::Acquire) != INITIALIZED {
+            self.id = Some(waiter::register_owned(
+                cell.waiter_key(),
+                cx.waker(),
+                self.id,
+            ));
+            // 登记后再次检查，以免错过登记前发生的唤醒。
+            if cell.state.load(Ordering::SeqCst) != INITIALIZED {
+                return Poll::Pending;
+            }
+        }
+        if let Some(id) = self.id.take() {
+            waiter::unregister_owned(id);
+        }
+        Poll::Ready(unsafe { cell.get_unchecked() })
+    }
+}
+
+impl<T: ?Sized> Drop for Wait<'_, T> {
+    fn drop(&mut self) {
+        if let Some(id) = self.id.take() {
+            waiter::unregister_owned(id);
+        }
+    }
+}
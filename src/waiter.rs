@@ -0,0 +1,229 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 等待初始化结束的任务的登记表。
+//!
+//! 为了不增加 [`OnceInit`](crate::OnceInit) 的大小、不改变其布局，所有 [`OnceInit`](crate::OnceInit)
+//! 共用同一张登记表，以 [`OnceInit`](crate::OnceInit) 的地址区分。
+//!
+//! 有 `std` 时登记表由 [`Mutex`](std::sync::Mutex) 保护；`no_std` 下则使用简单的自旋锁，
+//! 临界区内仅有少量的 [`Vec`] 操作。
+
+#[cfg(not(feature = "no_std"))]
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::{
+    ops::DerefMut,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::Waker,
+};
+#[cfg(not(feature = "no_std"))]
+use std::{
+    sync::{Mutex, PoisonError},
+    task::Wake,
+    thread::Thread,
+};
+
+/// 登记表中的一项。
+struct Entry {
+    /// 所等待的 [`OnceInit`](crate::OnceInit) 的地址。
+    key: usize,
+    /// 由 [`register_owned`] 登记时为非零的编号，以便所有者单独取消；否则为 `0`.
+    id: usize,
+    waker: Waker,
+}
+
+#[cfg(not(feature = "no_std"))]
+static WAITERS: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+#[cfg(feature = "no_std")]
+static WAITERS: spin::SpinLock<Vec<Entry>> = spin::SpinLock::new(Vec::new());
+/// 登记表中的任务数，为 `0` 时唤醒无需加锁。
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+/// 下一个由 [`register_owned`] 分配的编号。
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+#[cfg(not(feature = "no_std"))]
+fn lock() -> impl DerefMut<Target = Vec<Entry>> {
+    WAITERS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+#[cfg(feature = "no_std")]
+fn lock() -> impl DerefMut<Target = Vec<Entry>> {
+    WAITERS.lock()
+}
+
+#[cfg(feature = "no_std")]
+mod spin {
+    use core::{
+        cell::UnsafeCell,
+        ops::{Deref, DerefMut},
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    pub(super) struct SpinLock<T> {
+        locked: AtomicBool,
+        data: UnsafeCell<T>,
+    }
+
+    unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+    impl<T> SpinLock<T> {
+        pub(super) const fn new(data: T) -> Self {
+            Self {
+                locked: AtomicBool::new(false),
+                data: UnsafeCell::new(data),
+            }
+        }
+        pub(super) fn lock(&self) -> SpinGuard<'_, T> {
+            while self
+                .locked
+                .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                core::hint::spin_loop()
+            }
+            SpinGuard(self)
+        }
+    }
+
+    pub(super) struct SpinGuard<'a, T>(&'a SpinLock<T>);
+
+    impl<T> Deref for SpinGuard<'_, T> {
+        type Target = T;
+        fn deref(&self) -> &T {
+            unsafe { &*self.0.data.get() }
+        }
+    }
+    impl<T> DerefMut for SpinGuard<'_, T> {
+        fn deref_mut(&mut self) -> &mut T {
+            unsafe { &mut *self.0.data.get() }
+        }
+    }
+    impl<T> Drop for SpinGuard<'_, T> {
+        fn drop(&mut self) {
+            self.0.locked.store(false, Ordering::Release)
+        }
+    }
+}
+
+/// 登记任务，在 `key` 对应的 [`OnceInit`](crate::OnceInit) 初始化结束时唤醒。
+///
+/// 同一任务重复登记时不会产生新的登记项。
+pub(crate) fn register(key: usize, waker: &Waker) {
+    let mut waiters = lock();
+    if waiters
+        .iter()
+        .any(|e| e.key == key && e.id == 0 && e.waker.will_wake(waker))
+    {
+        return;
+    }
+    waiters.push(Entry {
+        key,
+        id: 0,
+        waker: waker.clone(),
+    });
+    COUNT.fetch_add(1, Ordering::SeqCst);
+}
+
+/// 取消登记，用于登记后发现无需等待的情形。
+pub(crate) fn unregister(key: usize, waker: &Waker) {
+    let mut waiters = lock();
+    if let Some(i) = waiters
+        .iter()
+        .position(|e| e.key == key && e.id == 0 && e.waker.will_wake(waker))
+    {
+        waiters.swap_remove(i);
+        COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 同 [`register`], 但返回登记项的编号，供所有者以 [`unregister_owned`] 单独取消。
+///
+/// 若 `previous` 对应的登记项仍在表中，则以新的 `waker` 替换之，因此同一所有者至多占用一个登记项。
+pub(crate) fn register_owned(key: usize, waker: &Waker, previous: Option<usize>) -> usize {
+    let mut waiters = lock();
+    if let Some(entry) = previous.and_then(|id| waiters.iter_mut().find(|e| e.id == id)) {
+        if !entry.waker.will_wake(waker) {
+            entry.waker = waker.clone();
+        }
+        return entry.id;
+    }
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    waiters.push(Entry {
+        key,
+        id,
+        waker: waker.clone(),
+    });
+    COUNT.fetch_add(1, Ordering::SeqCst);
+    id
+}
+
+/// 取消编号为 `id` 的登记项；已被唤醒（移出登记表）时什么也不做。
+pub(crate) fn unregister_owned(id: usize) {
+    let mut waiters = lock();
+    if let Some(i) = waiters.iter().position(|e| e.id == id) {
+        waiters.swap_remove(i);
+        COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 在 `key` 上等待的任务数，仅供测试使用。
+#[cfg(all(not(feature = "no_std"), test))]
+pub(crate) fn count(key: usize) -> usize {
+    lock().iter().filter(|e| e.key == key).count()
+}
+
+#[cfg(not(feature = "no_std"))]
+struct ThreadWaker(Thread);
+
+#[cfg(not(feature = "no_std"))]
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark()
+    }
+}
+
+/// 返回唤醒当前线程的 [`Waker`].
+#[cfg(not(feature = "no_std"))]
+pub(crate) fn thread_waker() -> Waker {
+    Waker::from(Arc::new(ThreadWaker(std::thread::current())))
+}
+
+/// 唤醒所有在 `key` 上等待的任务。
+pub(crate) fn wake_all(key: usize) {
+    if COUNT.load(Ordering::SeqCst) == 0 {
+        return;
+    }
+    let mut woken = Vec::new();
+    {
+        let mut waiters = lock();
+        let mut i = 0;
+        while i < waiters.len() {
+            if waiters[i].key == key {
+                woken.push(waiters.swap_remove(i).waker);
+            } else {
+                i += 1;
+            }
+        }
+        COUNT.fetch_sub(woken.len(), Ordering::SeqCst);
+    }
+    woken.into_iter().for_each(Waker::wake);
+}
@@ -0,0 +1,42 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! [`set_error_messages`] 会修改进程内所有 [`OnceInitError`] 的输出，
+//! 因此放在单独的测试二进制中，以免影响其他并发运行的测试。
+
+#![cfg(feature = "locale")]
+
+use onceinit::{set_error_messages, OnceInitError};
+
+#[test]
+fn test_set_error_messages() {
+    assert_eq!(
+        OnceInitError::DataUninitialized.to_string(),
+        "data is uninitialized."
+    );
+    set_error_messages("未初始化。", "已初始化。", "超时。").unwrap();
+    assert_eq!(OnceInitError::DataUninitialized.to_string(), "未初始化。");
+    assert_eq!(OnceInitError::DataInitialized.to_string(), "已初始化。");
+    assert_eq!(OnceInitError::TimedOut.to_string(), "超时。");
+    assert!(set_error_messages("a", "b", "c").is_err());
+    assert_eq!(OnceInitError::DataInitialized.to_string(), "已初始化。");
+}
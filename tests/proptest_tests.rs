@@ -0,0 +1,104 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 随机生成多线程的 `init`/`get` 调用序列，检查状态机的一致性。
+
+use onceinit::{OnceInit, OnceInitError};
+use proptest::{collection::vec, prelude::*};
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Barrier,
+};
+
+static VALUES: [u32; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Init,
+    Get,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![Just(Op::Init), Just(Op::Get)]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn concurrent_init_and_get(ops in vec(vec(op(), 1..16), 2..=VALUES.len())) {
+        let cell: OnceInit<u32> = OnceInit::uninit();
+        let successes = AtomicUsize::new(0);
+        // 某次 `init` 成功返回后置为 `true`.
+        let initialized = AtomicBool::new(false);
+        let barrier = Barrier::new(ops.len());
+        let observed: Vec<Vec<&'static u32>> = std::thread::scope(|s| {
+            let handles: Vec<_> = ops
+                .iter()
+                .enumerate()
+                .map(|(i, ops)| {
+                    let (cell, successes, initialized, barrier) =
+                        (&cell, &successes, &initialized, &barrier);
+                    s.spawn(move || {
+                        barrier.wait();
+                        let mut observed = Vec::new();
+                        for op in ops {
+                            match op {
+                                Op::Init => match cell.init(&VALUES[i]) {
+                                    Ok(()) => {
+                                        successes.fetch_add(1, Ordering::SeqCst);
+                                        initialized.store(true, Ordering::SeqCst);
+                                        observed.push(cell.get().unwrap());
+                                    }
+                                    Err(e) => assert!(matches!(e, OnceInitError::DataInitialized)),
+                                },
+                                Op::Get => {
+                                    let after_init = initialized.load(Ordering::SeqCst);
+                                    match cell.get() {
+                                        Ok(data) => observed.push(data),
+                                        Err(e) => {
+                                            assert!(!after_init, "get failed after init completed");
+                                            assert!(matches!(e, OnceInitError::DataUninitialized));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        observed
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let any_init = ops.iter().flatten().any(|op| matches!(op, Op::Init));
+        prop_assert_eq!(successes.load(Ordering::SeqCst), usize::from(any_init));
+        prop_assert_eq!(cell.is_initialized(), any_init);
+        if let Ok(winner) = cell.get() {
+            for data in observed.iter().flatten() {
+                prop_assert!(std::ptr::eq(*data, winner));
+            }
+        } else {
+            prop_assert!(observed.iter().all(Vec::is_empty));
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! 可在 Miri 下运行的用例：覆盖 `tests/ui/pass` 中被接受的 `Send`/`Sync` 组合的多线程用例，
+//! 以及 ffi 相关类型的 [`StaticDefault`](onceinit::StaticDefault) 的指针与长度检查。
+//!
+//! ```sh
+//! MIRIFLAGS=-Zmiri-ignore-leaks cargo +nightly miri test --test soundness
+//! ```
+//!
+//! 初始化的数据会被有意泄漏，因此需忽略 Miri 的泄漏检查。
+
+use onceinit::{OnceInit, OnceInitRef};
+#[cfg(not(feature = "no_std"))]
+use std::{marker::PhantomData, sync::MutexGuard};
+
+// `Sync` 而非 `Send` 的类型。
+#[cfg(not(feature = "no_std"))]
+struct SyncOnly(u32, PhantomData<MutexGuard<'static, ()>>);
+
+#[cfg(any(feature = "alloc", not(feature = "no_std")))]
+#[test]
+fn init_boxed_race() {
+    let cell: OnceInit<Vec<u32>> = OnceInit::uninit();
+    std::thread::scope(|s| {
+        for i in 0..4 {
+            let cell = &cell;
+            s.spawn(move || {
+                let _ = cell.init_boxed(Box::new(vec![i; 4]));
+                let data = cell.get().unwrap();
+                assert!(data.iter().all(|&x| x == data[0]));
+            });
+        }
+    });
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn sync_not_send() {
+    let cell: OnceInit<SyncOnly> = OnceInit::uninit();
+    std::thread::scope(|s| {
+        s.spawn(|| cell.init_boxed(Box::new(SyncOnly(7, PhantomData))));
+        s.spawn(|| assert_eq!(cell.wait_initialized().0, 7));
+    });
+    let moved = OnceInit::new(cell.get().unwrap());
+    std::thread::spawn(move || assert_eq!(moved.get().unwrap().0, 7))
+        .join()
+        .unwrap();
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn dyn_sync() {
+    let cell: OnceInit<dyn Fn() -> u32 + Sync> = OnceInit::uninit();
+    std::thread::scope(|s| {
+        s.spawn(|| cell.init_boxed(Box::new(|| 3)));
+        s.spawn(|| assert_eq!((cell.wait_initialized())(), 3));
+    });
+}
+
+#[test]
+fn scoped_ref() {
+    let data = [1u32, 2, 3];
+    let cell = OnceInitRef::uninit();
+    std::thread::scope(|s| {
+        s.spawn(|| cell.init(&data[..]));
+        s.spawn(|| {
+            if let Ok(data) = cell.get() {
+                assert_eq!(data, [1, 2, 3]);
+            }
+        });
+    });
+}
+
+#[test]
+fn ffi_static_defaults() {
+    use std::ffi::{c_char, CStr};
+    let name: OnceInit<CStr> = OnceInit::uninit();
+    let ptr = name.as_ptr();
+    assert!(!ptr.is_null());
+    // 读取 NUL 结尾，在 Miri 下检查指针的来源与边界。
+    assert_eq!(unsafe { *ptr }, 0);
+    assert_eq!(unsafe { CStr::from_ptr(ptr) }.to_bytes_with_nul(), [0]);
+    assert_eq!(name.get_or_default().count_bytes(), 0);
+
+    let bytes: OnceInit<[u8]> = OnceInit::uninit();
+    let c_chars: OnceInit<[i8]> = OnceInit::uninit();
+    let c_chars_alias: OnceInit<[c_char]> = OnceInit::uninit();
+    for (ptr, len) in [
+        (
+            bytes.get_or_default().as_ptr(),
+            bytes.get_or_default().len(),
+        ),
+        (
+            c_chars.get_or_default().as_ptr().cast(),
+            c_chars.get_or_default().len(),
+        ),
+        (
+            c_chars_alias.get_or_default().as_ptr().cast(),
+            c_chars_alias.get_or_default().len(),
+        ),
+    ] {
+        assert!(!ptr.is_null());
+        assert_eq!(len, 0);
+        // 空切片的指针非空且对齐，可以重新构造切片。
+        assert!(unsafe { std::slice::from_raw_parts(ptr, len) }.is_empty());
+    }
+}
@@ -0,0 +1,36 @@
+// MIT License
+//
+// Copyright (c) 2024 worksoup <https://github.com/worksoup/>
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use onceinit::OnceInit;
+use std::{marker::PhantomData, sync::MutexGuard};
+
+// `Sync` 而非 `Send` 的类型。
+struct SyncOnly(PhantomData<MutexGuard<'static, ()>>);
+
+static CELL: OnceInit<SyncOnly> = OnceInit::uninit();
+
+fn main() {
+    CELL.init_boxed(Box::new(SyncOnly(PhantomData))).unwrap();
+    std::thread::spawn(|| CELL.get().map(|_| ())).join().unwrap().unwrap();
+    let local: OnceInit<SyncOnly> = OnceInit::new(CELL.get().unwrap());
+    std::thread::spawn(move || local.get().map(|_| ())).join().unwrap().unwrap();
+}